@@ -0,0 +1,93 @@
+//! Validates the `content/` tree at compile time so content mistakes fail
+//! the build instead of panicking in the browser, then merges it into the
+//! single document embedded into the site.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+#[allow(dead_code)]
+#[path = "src/content/date.rs"]
+mod date;
+#[allow(dead_code)]
+#[path = "src/content/draft.rs"]
+mod draft;
+#[allow(dead_code)]
+#[path = "src/content/fallback.rs"]
+mod fallback;
+#[allow(dead_code)]
+#[path = "src/content/filter.rs"]
+mod filter;
+#[allow(dead_code)]
+#[path = "src/content/icon.rs"]
+mod icon;
+#[allow(dead_code)]
+#[path = "src/content/lang.rs"]
+mod lang;
+#[allow(dead_code)]
+#[path = "src/content/markdown.rs"]
+mod markdown;
+#[allow(dead_code)]
+#[path = "src/content/model.rs"]
+mod model;
+#[allow(dead_code)]
+#[path = "src/content/overlay.rs"]
+mod overlay;
+#[allow(dead_code)]
+#[path = "src/content/rich_text.rs"]
+mod rich_text;
+#[allow(dead_code)]
+#[path = "src/content/sanitize.rs"]
+mod sanitize;
+#[allow(dead_code)]
+#[path = "src/content/skills.rs"]
+mod skills;
+#[allow(dead_code)]
+#[path = "src/content/tree.rs"]
+mod tree;
+#[allow(dead_code)]
+#[path = "src/content/validate.rs"]
+mod validate;
+#[allow(dead_code)]
+#[path = "src/content/variables.rs"]
+mod variables;
+
+const CONTENT_DIR: &str = "content";
+
+fn main() {
+    println!("cargo:rerun-if-changed={CONTENT_DIR}");
+
+    let overlays = selected_overlays();
+    println!("cargo:rustc-env=CONTENT_OVERLAYS={}", overlays.join(","));
+    let options = tree::Options {
+        overlays,
+        // Follows `cfg!(debug_assertions)` of the site the content is built into
+        strip_drafts: env::var_os("CARGO_CFG_DEBUG_ASSERTIONS").is_none(),
+    };
+    let tree = match tree::load(Path::new(CONTENT_DIR), &options) {
+        Ok(tree) => tree,
+        Err(message) => {
+            eprintln!("error: {message}");
+            process::exit(1);
+        }
+    };
+
+    for stripped in tree.content.sanitizer_report() {
+        println!("cargo:warning={CONTENT_DIR}: {stripped}");
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    let merged = Path::new(&out_dir).join("content.yaml");
+    if let Err(err) = fs::write(&merged, tree.merged) {
+        eprintln!("error: {}: {err}", merged.display());
+        process::exit(1);
+    }
+}
+
+/// Overlays named in `CONTENT_OVERLAY`, comma-separated. `content dist` reads
+/// the same variable, so the published files match the embedded copy.
+fn selected_overlays() -> Vec<String> {
+    println!("cargo:rerun-if-env-changed=CONTENT_OVERLAY");
+    overlay::split_names(&env::var("CONTENT_OVERLAY").unwrap_or_default())
+}
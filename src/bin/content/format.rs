@@ -0,0 +1,280 @@
+use serde_yaml::{Mapping, Value};
+
+// Formatter behind `content fmt`. The document is parsed and written back in
+// one style: two-space indentation, indented list items, double-quoted
+// strings and literal blocks for multi-line text. Short lists of strings such
+// as `technologies` stay on one line. Comments at the top of the file, such as
+// the schema modeline, are kept. The parsed document has no place for other
+// comments, so a file with one is refused rather than formatted without it.
+
+/// Widest line a list of strings may take when written inline
+const MAX_INLINE_WIDTH: usize = 120;
+
+/// Normalize a YAML document, failing if the result would parse differently
+pub fn format(source: &str) -> Result<String, String> {
+    let value: Value = serde_yaml::from_str(source).map_err(|err| err.to_string())?;
+
+    let top = source
+        .lines()
+        .take_while(|line| line.starts_with('#') || line.trim().is_empty())
+        .count();
+    if let Some(line) = comment_below_top(source, top, &value) {
+        return Err(format!(
+            "line {line}: comments are only kept at the top of the file, move or remove this one"
+        ));
+    }
+
+    let mut out = String::new();
+    let header: Vec<&str> = source
+        .lines()
+        .take(top)
+        .filter(|line| line.starts_with('#'))
+        .collect();
+    for line in &header {
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    if !header.is_empty() {
+        out.push('\n');
+    }
+
+    match &value {
+        Value::Mapping(mapping) if !mapping.is_empty() => {
+            write_mapping(&mut out, mapping, 0, false)?
+        }
+        Value::Sequence(items) if !items.is_empty() => write_sequence(&mut out, items, 0, false)?,
+        scalar => write_scalar(&mut out, scalar, 0)?,
+    }
+
+    let reparsed: Value = serde_yaml::from_str(&out).map_err(|err| err.to_string())?;
+    if reparsed != value {
+        return Err("formatting would change the content".to_owned());
+    }
+    Ok(out)
+}
+
+/// Number of the first line after the top `skip` lines that holds a comment.
+/// A `#` starts one when cutting the line there still parses to `value`, which
+/// tells it apart from a `#` inside quotes or a literal block.
+fn comment_below_top(source: &str, skip: usize, value: &Value) -> Option<usize> {
+    let lines: Vec<&str> = source.lines().collect();
+    (skip..lines.len())
+        .find(|&index| {
+            let line = lines[index];
+            line.char_indices()
+                .filter(|&(at, c)| c == '#' && (at == 0 || line[..at].ends_with([' ', '\t'])))
+                .any(|(at, _)| {
+                    let mut cut = lines.clone();
+                    cut[index] = &line[..at];
+                    serde_yaml::from_str::<Value>(&cut.join("\n")).is_ok_and(|cut| cut == *value)
+                })
+        })
+        .map(|index| index + 1)
+}
+
+fn write_mapping(
+    out: &mut String,
+    mapping: &Mapping,
+    indent: usize,
+    inline_first: bool,
+) -> Result<(), String> {
+    for (index, (key, value)) in mapping.iter().enumerate() {
+        if index > 0 || !inline_first {
+            pad(out, indent);
+        }
+        let Value::String(key) = key else {
+            return Err(format!("unsupported key `{key:?}`, keys must be strings"));
+        };
+        if is_plain_key(key) {
+            out.push_str(key);
+        } else {
+            push_quoted(out, key);
+        }
+        out.push(':');
+
+        match value {
+            Value::Mapping(mapping) if !mapping.is_empty() => {
+                out.push('\n');
+                write_mapping(out, mapping, indent + 2, false)?;
+            }
+            Value::Sequence(items) if !items.is_empty() => {
+                let width = out.len() - out.rfind('\n').map_or(0, |end| end + 1);
+                match inline_strings(items) {
+                    Some(inline) if width + 1 + inline.chars().count() <= MAX_INLINE_WIDTH => {
+                        out.push(' ');
+                        out.push_str(&inline);
+                        out.push('\n');
+                    }
+                    _ => {
+                        out.push('\n');
+                        write_sequence(out, items, indent + 2, false)?;
+                    }
+                }
+            }
+            scalar => {
+                out.push(' ');
+                write_scalar(out, scalar, indent)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_sequence(
+    out: &mut String,
+    items: &[Value],
+    indent: usize,
+    inline_first: bool,
+) -> Result<(), String> {
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 || !inline_first {
+            pad(out, indent);
+        }
+        out.push_str("- ");
+        match item {
+            Value::Mapping(mapping) if !mapping.is_empty() => {
+                write_mapping(out, mapping, indent + 2, true)?
+            }
+            Value::Sequence(items) if !items.is_empty() => {
+                write_sequence(out, items, indent + 2, true)?
+            }
+            scalar => write_scalar(out, scalar, indent)?,
+        }
+    }
+    Ok(())
+}
+
+/// A list of single-line strings written as `["a", "b"]`
+fn inline_strings(items: &[Value]) -> Option<String> {
+    let mut inline = String::from("[");
+    for (index, item) in items.iter().enumerate() {
+        let text = item.as_str().filter(|text| !text.contains('\n'))?;
+        if index > 0 {
+            inline.push_str(", ");
+        }
+        push_quoted(&mut inline, text);
+    }
+    inline.push(']');
+    Some(inline)
+}
+
+/// Write a scalar and the line break after it; literal block lines go at `indent + 2`
+fn write_scalar(out: &mut String, value: &Value, indent: usize) -> Result<(), String> {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(value) => out.push_str(&value.to_string()),
+        Value::Number(value) => out.push_str(&value.to_string()),
+        Value::String(value) if fits_literal_block(value) => {
+            let body = value.trim_end_matches('\n');
+            let chomping = match value.len() - body.len() {
+                0 => "-",
+                1 => "",
+                _ => "+",
+            };
+            out.push('|');
+            out.push_str(chomping);
+            for line in body.split('\n') {
+                out.push('\n');
+                if !line.is_empty() {
+                    pad(out, indent + 2);
+                    out.push_str(line);
+                }
+            }
+            for _ in 1..value.len() - body.len() {
+                out.push('\n');
+            }
+        }
+        Value::String(value) => push_quoted(out, value),
+        Value::Mapping(_) => out.push_str("{}"),
+        Value::Sequence(_) => out.push_str("[]"),
+        Value::Tagged(tagged) => return Err(format!("unsupported tag `{}`", tagged.tag)),
+    }
+    out.push('\n');
+    Ok(())
+}
+
+/// Multi-line text that a literal block can hold without an indentation indicator
+fn fits_literal_block(value: &str) -> bool {
+    value.contains('\n')
+        && !value.starts_with([' ', '\n'])
+        && !value
+            .chars()
+            .any(|c| c.is_control() && c != '\n' && c != '\t')
+}
+
+fn is_plain_key(key: &str) -> bool {
+    key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+}
+
+fn push_quoted(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn pad(out: &mut String, indent: usize) {
+    out.extend(std::iter::repeat_n(' ', indent));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_to_house_style() {
+        let source = "# yaml-language-server: $schema=../../schema/about.json\n\
+            title: Обо мне\n\
+            description: |\n  First line.\n\n  Second \"line\".\n\
+            years: [год, года, лет]\n\
+            skills:\n- a very long line of text that cannot fit on one line next to the other entry of the list\n- and another line of text that goes on\n\
+            work_experience:\n- start: 2022-02\n  end: null\n  description: []\n";
+
+        assert_eq!(
+            format(source).unwrap(),
+            "# yaml-language-server: $schema=../../schema/about.json\n\
+            \n\
+            title: \"Обо мне\"\n\
+            description: |\n  First line.\n\n  Second \"line\".\n\
+            years: [\"год\", \"года\", \"лет\"]\n\
+            skills:\n  - \"a very long line of text that cannot fit on one line next to the other entry of the list\"\n  - \"and another line of text that goes on\"\n\
+            work_experience:\n  - start: \"2022-02\"\n    end: null\n    description: []\n"
+        );
+    }
+
+    #[test]
+    fn refuses_to_drop_comments_below_the_top() {
+        let source = "# schema\n\n\
+            title: \"Hi # there\"\n\
+            text: |\n  # heading\n  body\n\
+            # about the phone\n\
+            phone: \"+7\"\n";
+        assert_eq!(
+            format(source).unwrap_err(),
+            "line 7: comments are only kept at the top of the file, move or remove this one"
+        );
+        assert_eq!(
+            format("email: i@itmage.ru # work\n").unwrap_err(),
+            "line 1: comments are only kept at the top of the file, move or remove this one"
+        );
+        assert!(format("# schema\ntitle: \"Hi # there\"\ntext: |\n  # heading\n").is_ok());
+    }
+
+    #[test]
+    fn formatting_is_stable() {
+        let source = "- ru\n- \"en\\ttab\"\n- key: \"a\\nb\\n\\n\"\n  nested:\n    - - deep\n";
+        let once = format(source).unwrap();
+        assert_eq!(format(&once).unwrap(), once);
+    }
+}
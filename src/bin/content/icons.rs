@@ -0,0 +1,235 @@
+use image::codecs::ico::{IcoEncoder, IcoFrame};
+use image::imageops::{self, FilterType};
+use image::{ExtendedColorType, ImageFormat, Rgba, RgbaImage};
+use resvg::{tiny_skia, usvg};
+use serde_json::{Value, json};
+use std::fs;
+use std::path::Path;
+
+// Favicons, the Apple touch icon and the web manifest icons, all rendered from
+// the logo set in `hero.logo` of the default language, or from the terminal
+// icon the hero falls back to. The links are injected into the `index.html`
+// of the same directory.
+
+/// Fallback logo: the hero's terminal icon, green on the dark tile
+const TERMINAL: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24">
+<rect width="24" height="24" rx="5" fill="#1f2937"/>
+<g transform="translate(4 4) scale(0.667)" fill="none" stroke="#86efac" stroke-width="2" stroke-linecap="round" stroke-linejoin="round">
+<polyline points="4 17 10 11 4 5"/><line x1="12" y1="19" x2="20" y2="19"/>
+</g>
+</svg>"##;
+
+/// Tile colour behind the logo in the hero, also used where icons may not be transparent
+const BACKGROUND: Rgba<u8> = Rgba([0x1f, 0x29, 0x37, 0xff]);
+const BACKGROUND_HEX: &str = "#1f2937";
+/// Colour of the hero bar, for the browser UI
+const THEME_HEX: &str = "#bbf7d0";
+
+/// Size the source is rendered at before it is scaled down for each icon
+const SOURCE_SIZE: u32 = 512;
+/// Sizes packed into `favicon.ico`
+const ICO_SIZES: &[u32] = &[16, 32, 48];
+/// PNG icons as file name, size and whether transparency is allowed
+const PNG_ICONS: &[(&str, u32, bool)] = &[
+    ("favicon-16x16.png", 16, true),
+    ("favicon-32x32.png", 32, true),
+    ("apple-touch-icon.png", 180, false),
+    ("icon-192.png", 192, true),
+    ("icon-512.png", 512, true),
+];
+const MANIFEST: &str = "site.webmanifest";
+
+/// Comments around the injected links, so running again replaces them
+const LINKS_START: &str = "<!-- icons: generated by `content dist` -->";
+const LINKS_END: &str = "<!-- /icons -->";
+
+/// Write every icon and the manifest into `dir` and link them from its `index.html`
+pub fn write(dir: &Path, logo: Option<&Path>, name: &str) -> Result<(), String> {
+    let source = match logo {
+        Some(path) => load(path)?,
+        None => render_svg(TERMINAL.as_bytes())?,
+    };
+
+    let frames = ICO_SIZES
+        .iter()
+        .map(|size| {
+            let icon = scale(&source, *size);
+            IcoFrame::as_png(icon.as_raw(), *size, *size, ExtendedColorType::Rgba8)
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("favicon.ico: {err}"))?;
+    let mut ico = Vec::new();
+    IcoEncoder::new(&mut ico)
+        .encode_images(&frames)
+        .map_err(|err| format!("favicon.ico: {err}"))?;
+    write_file(&dir.join("favicon.ico"), &ico)?;
+
+    for (file, size, transparent) in PNG_ICONS {
+        let mut icon = scale(&source, *size);
+        if !transparent {
+            icon = flatten(&icon);
+        }
+        let path = dir.join(file);
+        icon.save_with_format(&path, ImageFormat::Png)
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+    }
+
+    write_file(&dir.join(MANIFEST), manifest(name).as_bytes())?;
+
+    let index = dir.join("index.html");
+    let html = fs::read_to_string(&index).map_err(|err| format!("{}: {err}", index.display()))?;
+    let html = inject_links(&html).ok_or_else(|| format!("{}: no `</head>`", index.display()))?;
+    write_file(&index, html.as_bytes())
+}
+
+/// Load an SVG, PNG or JPEG logo as a square image of `SOURCE_SIZE`
+fn load(path: &Path) -> Result<RgbaImage, String> {
+    let data = fs::read(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    let is_svg = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"));
+    let image = if is_svg {
+        render_svg(&data)
+    } else {
+        image::load_from_memory(&data)
+            .map(|image| fit(&image.into_rgba8(), SOURCE_SIZE))
+            .map_err(|err| err.to_string())
+    };
+    image.map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Rasterize an SVG centered on a transparent square of `SOURCE_SIZE`
+fn render_svg(data: &[u8]) -> Result<RgbaImage, String> {
+    let tree =
+        usvg::Tree::from_data(data, &usvg::Options::default()).map_err(|err| err.to_string())?;
+    let size = tree.size();
+    let scale = SOURCE_SIZE as f32 / size.width().max(size.height());
+    let offset = |length: f32| (SOURCE_SIZE as f32 - length * scale) / 2.0;
+    let transform = tiny_skia::Transform::from_scale(scale, scale)
+        .post_translate(offset(size.width()), offset(size.height()));
+
+    let mut pixmap =
+        tiny_skia::Pixmap::new(SOURCE_SIZE, SOURCE_SIZE).ok_or("cannot allocate the icon")?;
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    let png = pixmap.encode_png().map_err(|err| err.to_string())?;
+    image::load_from_memory(&png)
+        .map(|image| image.into_rgba8())
+        .map_err(|err| err.to_string())
+}
+
+/// Scale an image to fit a transparent square, keeping its aspect ratio
+fn fit(image: &RgbaImage, size: u32) -> RgbaImage {
+    let scaled = imageops::resize(
+        image,
+        (image.width() * size / image.width().max(image.height())).max(1),
+        (image.height() * size / image.width().max(image.height())).max(1),
+        FilterType::Lanczos3,
+    );
+    let mut square = RgbaImage::new(size, size);
+    let x = (size - scaled.width()) / 2;
+    let y = (size - scaled.height()) / 2;
+    imageops::overlay(&mut square, &scaled, i64::from(x), i64::from(y));
+    square
+}
+
+fn scale(source: &RgbaImage, size: u32) -> RgbaImage {
+    imageops::resize(source, size, size, FilterType::Lanczos3)
+}
+
+/// Put an icon on the background colour, for places that show transparency as black
+fn flatten(icon: &RgbaImage) -> RgbaImage {
+    let mut flat = RgbaImage::from_pixel(icon.width(), icon.height(), BACKGROUND);
+    imageops::overlay(&mut flat, icon, 0, 0);
+    flat
+}
+
+fn manifest(name: &str) -> String {
+    let icons: Vec<Value> = PNG_ICONS
+        .iter()
+        .filter(|(file, ..)| file.starts_with("icon-"))
+        .map(|(file, size, _)| {
+            json!({
+                "src": format!("/{file}"),
+                "sizes": format!("{size}x{size}"),
+                "type": "image/png",
+            })
+        })
+        .collect();
+    let manifest = json!({
+        "name": name,
+        "short_name": name,
+        "icons": icons,
+        "theme_color": THEME_HEX,
+        "background_color": BACKGROUND_HEX,
+        "display": "browser",
+    });
+    serde_json::to_string_pretty(&manifest).unwrap_or_default() + "\n"
+}
+
+/// Insert the icon links at the end of `<head>`, replacing ones injected before
+fn inject_links(html: &str) -> Option<String> {
+    let line_start = |at: usize| html[..at].rfind('\n').map_or(0, |newline| newline + 1);
+    let html = match (html.find(LINKS_START), html.find(LINKS_END)) {
+        (Some(start), Some(end)) if start < end => {
+            let end = end + LINKS_END.len();
+            let end = html[end..]
+                .find('\n')
+                .map_or(html.len(), |newline| end + newline + 1);
+            format!("{}{}", &html[..line_start(start)], &html[end..])
+        }
+        _ => html.to_owned(),
+    };
+
+    let head = html.find("</head>")?;
+    // On a line of its own `</head>` gives the indentation, otherwise insert right before it
+    let line = html[..head].rfind('\n').map_or(0, |newline| newline + 1);
+    let (start, indent) = match &html[line..head] {
+        indent if indent.trim().is_empty() => (line, indent),
+        _ => (head, ""),
+    };
+    let links = [
+        LINKS_START.to_owned(),
+        r#"<link rel="icon" href="/favicon.ico" sizes="48x48" />"#.to_owned(),
+        r#"<link rel="icon" type="image/png" sizes="32x32" href="/favicon-32x32.png" />"#
+            .to_owned(),
+        r#"<link rel="icon" type="image/png" sizes="16x16" href="/favicon-16x16.png" />"#
+            .to_owned(),
+        r#"<link rel="apple-touch-icon" sizes="180x180" href="/apple-touch-icon.png" />"#
+            .to_owned(),
+        format!(r#"<link rel="manifest" href="/{MANIFEST}" />"#),
+        format!(r#"<meta name="theme-color" content="{THEME_HEX}" />"#),
+        LINKS_END.to_owned(),
+    ];
+    let links: String = links
+        .iter()
+        .map(|link| format!("{indent}    {link}\n"))
+        .collect();
+    Some(format!("{}{links}{}", &html[..start], &html[start..]))
+}
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_the_fallback_and_injects_links_once() {
+        let source = render_svg(TERMINAL.as_bytes()).unwrap();
+        assert_eq!(source.dimensions(), (SOURCE_SIZE, SOURCE_SIZE));
+        assert_eq!(*source.get_pixel(256, 256), BACKGROUND);
+        assert_eq!(source.get_pixel(0, 0).0[3], 0);
+        assert_eq!(flatten(&scale(&source, 180)).get_pixel(0, 0), &BACKGROUND);
+
+        let html = "<html>\n  <head>\n    <title>x</title>\n  </head>\n</html>\n";
+        let once = inject_links(html).unwrap();
+        assert!(once.contains("\n      <link rel=\"apple-touch-icon\""));
+        assert!(once.ends_with("<!-- /icons -->\n  </head>\n</html>\n"));
+        assert_eq!(inject_links(&once).unwrap(), once);
+        assert!(inject_links("<p>no head</p>").is_none());
+        let inline = inject_links("<head><title>x</title></head>").unwrap();
+        assert!(inline.starts_with("<head><title>x</title>    <!-- icons"));
+    }
+}
@@ -0,0 +1,332 @@
+//! Host-side tool for content editors: validates, summarizes, queries and
+//! formats the `content/` tree without building the site.
+
+use serde_yaml::Value;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use itmagelab::content::date::total_experience;
+use itmagelab::content::markdown;
+use itmagelab::content::model::fallback_for;
+use itmagelab::content::overlay::split_names;
+use itmagelab::content::parity::parity_report;
+use itmagelab::content::tree::{self, Options};
+use itmagelab::content::variables;
+use itmagelab::content::{Content, Lang};
+
+mod format;
+mod icons;
+mod pdf;
+mod resume;
+mod vcard;
+
+const USAGE: &str = "\
+Usage: content <command>
+
+Commands:
+  validate       Check every content file, link and overlay, with and without drafts,
+                 and report warnings
+  stats          Count projects, skills and years of experience per language
+  get <path>     Print one value with variables resolved, e.g. `en.contact.email` or
+                 `ru.portfolio.projects[0].name`
+  fmt [--check]  Normalize the formatting of every content file
+  dist <dir>     Publish the merged content as `<dir>/content.yaml`, prepared like the
+                 build with CONTENT_OVERLAY and CONTENT_RELEASE, and generate from it
+                 favicons, touch and manifest icons, linked from `<dir>/index.html`,
+                 a JSON Resume, a PDF and a Markdown résumé, a vCard and its QR code
+                 per language
+
+Environment:
+  CONTENT_DIR      Content directory, `content` by default
+  CONTENT_OVERLAY  Overlays to apply, comma-separated, as in the build
+  CONTENT_RELEASE  Leave out drafts as release builds do when set
+";
+
+fn main() -> ExitCode {
+    let dir = env::var_os("CONTENT_DIR").map_or_else(|| PathBuf::from("content"), PathBuf::from);
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let options = Options {
+        overlays: split_names(&env::var("CONTENT_OVERLAY").unwrap_or_default()),
+        strip_drafts: env::var_os("CONTENT_RELEASE").is_some(),
+    };
+
+    let result = match args.as_slice() {
+        ["validate"] => validate(&dir, &options),
+        ["stats"] => stats(&dir, &options),
+        ["get", path] => get(&dir, &options, path),
+        ["fmt"] => fmt(&dir, false),
+        ["fmt", "--check"] => fmt(&dir, true),
+        ["dist", out] => dist(&dir, &options, Path::new(out)),
+        ["help" | "--help" | "-h"] => {
+            print!("{USAGE}");
+            Ok(())
+        }
+        _ => Err(format!("unexpected arguments\n\n{USAGE}")),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Load the tree like the build does, then report everything that is suspicious but valid
+fn validate(dir: &Path, options: &Options) -> Result<(), String> {
+    let tree = tree::load(dir, options)?;
+    // Any overlay may be picked for a debug or release build, so each one must apply on its own
+    for overlay in std::iter::once(None).chain(tree::overlays(dir)?.into_iter().map(Some)) {
+        for strip_drafts in [false, true] {
+            let options = Options {
+                overlays: overlay.iter().cloned().collect(),
+                strip_drafts,
+            };
+            tree::load(dir, &options)?;
+        }
+    }
+
+    // Asset paths such as `/static/photo.jpg` are relative to the directory holding `content/`
+    let site_root = dir.parent().unwrap_or(Path::new("."));
+    let broken = tree.content.link_report(site_root);
+    if !broken.is_empty() {
+        let lines: Vec<String> = broken.iter().map(ToString::to_string).collect();
+        return Err(format!("broken links:\n  {}", lines.join("\n  ")));
+    }
+
+    let mut warnings: Vec<String> = tree
+        .substitutions
+        .iter()
+        .map(|substitution| {
+            format!(
+                "{}: missing translation, using `{}`",
+                substitution.path, substitution.from
+            )
+        })
+        .collect();
+    let raw: Value = serde_yaml::from_str(&tree.merged).map_err(|err| err.to_string())?;
+    warnings.extend(parity_report(&raw).iter().map(ToString::to_string));
+    warnings.extend(tree.content.sanitizer_report());
+
+    for warning in &warnings {
+        eprintln!("warning: {warning}");
+    }
+    println!(
+        "{}: {} languages in {} files, {} warnings",
+        dir.display(),
+        tree.content.languages().count(),
+        tree.files.len(),
+        warnings.len()
+    );
+    Ok(())
+}
+
+fn stats(dir: &Path, options: &Options) -> Result<(), String> {
+    let tree = tree::load(dir, options)?;
+    let today = tree::today()?;
+
+    println!(
+        "{:<8} {:>8} {:>8} {:>8}",
+        "language", "projects", "skills", "years"
+    );
+    for (lang, content) in tree.content.languages() {
+        let experience = total_experience(&content.about.work_experience, today);
+        let years = f64::from(experience.years) + f64::from(experience.months) / 12.0;
+        println!(
+            "{:<8} {:>8} {:>8} {:>8.1}",
+            lang.as_str(),
+            content.portfolio.projects.len(),
+            content.portfolio.skills.len(),
+            years
+        );
+    }
+    Ok(())
+}
+
+/// Print a value as the site shows it, with `{variables}` resolved
+fn get(dir: &Path, options: &Options, path: &str) -> Result<(), String> {
+    let tree = tree::load(dir, options)?;
+    let mut raw: Value = serde_yaml::from_str(&tree.merged).map_err(|err| err.to_string())?;
+    variables::resolve(&mut raw, &tree.content, tree::today()?)?;
+
+    match lookup(&raw, path)? {
+        Value::String(text) => println!("{text}"),
+        value => print!(
+            "{}",
+            serde_yaml::to_string(value).map_err(|err| err.to_string())?
+        ),
+    }
+    Ok(())
+}
+
+/// Find the value at a path such as `ru.portfolio.projects[0].name`, taking it
+/// from the fallback language when the translation leaves it out, as the site does
+fn lookup<'a>(raw: &'a Value, path: &str) -> Result<&'a Value, String> {
+    let (lang, rest) = path.split_once('.').unwrap_or((path, ""));
+    let lang: Lang = lang.parse()?;
+    let segments = parse_path(rest).ok_or_else(|| format!("invalid path `{path}`"))?;
+
+    let find = |root: &'a Value| {
+        segments
+            .iter()
+            .try_fold(root, |value, segment| match segment {
+                Segment::Key(key) => value.get(key),
+                Segment::Index(index) => value.get(index),
+            })
+    };
+
+    let Some(content) = raw.get(lang.as_str()) else {
+        return Err(format!("language `{lang}` is not defined"));
+    };
+    if let Some(value) = find(content) {
+        return Ok(value);
+    }
+    fallback_for(raw, &lang)?
+        .and_then(|(_, fallback)| find(fallback))
+        .ok_or_else(|| format!("`{path}` is not defined"))
+}
+
+enum Segment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+/// Split `portfolio.projects[0].name` into keys and list indices
+fn parse_path(path: &str) -> Option<Vec<Segment<'_>>> {
+    let mut segments = Vec::new();
+    if path.is_empty() {
+        return Some(segments);
+    }
+    for part in path.split('.') {
+        let (key, mut indices) = part
+            .split_once('[')
+            .map_or((part, ""), |(key, rest)| (key, rest));
+        if key.is_empty() {
+            return None;
+        }
+        segments.push(Segment::Key(key));
+        while !indices.is_empty() {
+            let (index, rest) = indices.split_once(']')?;
+            segments.push(Segment::Index(index.parse().ok()?));
+            indices = match rest {
+                "" => "",
+                rest => rest.strip_prefix('[')?,
+            };
+        }
+    }
+    Some(segments)
+}
+
+/// Rewrite every content file in the house style, or with `check` only list the ones that differ
+fn fmt(dir: &Path, check: bool) -> Result<(), String> {
+    let mut files = tree::load(dir, &Options::default())?.files;
+    for name in tree::overlays(dir)? {
+        files.push(tree::overlay_path(dir, &name));
+    }
+
+    let mut unformatted = Vec::new();
+    for path in &files {
+        let source =
+            fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let formatted =
+            format::format(&source).map_err(|err| format!("{}: {err}", path.display()))?;
+        if formatted == source {
+            continue;
+        }
+        if !check {
+            fs::write(path, formatted).map_err(|err| format!("{}: {err}", path.display()))?;
+            println!("formatted {}", path.display());
+        }
+        unformatted.push(path.display().to_string());
+    }
+
+    if check && !unformatted.is_empty() {
+        return Err(format!(
+            "files are not formatted, run `content fmt`:\n  {}",
+            unformatted.join("\n  ")
+        ));
+    }
+    Ok(())
+}
+
+/// Publish the content into the site at `dir` and generate files from it, so
+/// they match what the site shows. The tree is merged again with the overlays
+/// and draft stripping the build was run with rather than taken from the build
+/// output, where other builds leave their own merged copies.
+fn dist(content_dir: &Path, options: &Options, dir: &Path) -> Result<(), String> {
+    let tree = tree::load(content_dir, options)?;
+    let path = dir.join("content.yaml");
+    fs::write(&path, &tree.merged).map_err(|err| format!("{}: {err}", path.display()))?;
+    let today = tree::today()?;
+    let (content, _) = Content::from_yaml(&tree.merged, today)
+        .map_err(|err| format!("{}: {err}", path.display()))?;
+    let hero = &content.get_or_default(content.default_lang()).hero;
+
+    // Logo paths such as `/static/logo.svg` are relative to the site root
+    let logo = hero
+        .logo
+        .as_ref()
+        .map(|logo| dir.join(logo.trim_start_matches('/')));
+    icons::write(dir, logo.as_deref(), &hero.title)?;
+
+    for (lang, content) in content.languages() {
+        let path = dir.join(resume::file_name(lang.as_str()));
+        let json = serde_json::to_string_pretty(&resume::Resume::new(lang.as_str(), content))
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        fs::write(&path, json + "\n").map_err(|err| format!("{}: {err}", path.display()))?;
+
+        let path = dir.join(pdf::file_name(lang.as_str()));
+        let cv = pdf::render(content, today).map_err(|err| format!("{}: {err}", path.display()))?;
+        fs::write(&path, cv).map_err(|err| format!("{}: {err}", path.display()))?;
+
+        let path = dir.join(markdown::file_name(lang.as_str()));
+        fs::write(&path, markdown::cv(content, today))
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+
+        let card = vcard::new(&content.hero.subtitle, &content.contact);
+        let path = dir.join(vcard::qr_file_name(lang.as_str()));
+        let qr = vcard::qr(&card).map_err(|err| format!("{}: {err}", path.display()))?;
+        fs::write(&path, qr).map_err(|err| format!("{}: {err}", path.display()))?;
+        let path = dir.join(vcard::file_name(lang.as_str()));
+        fs::write(&path, card).map_err(|err| format!("{}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The repository's content tree with every draft, shared by the tests of
+    /// the generated files
+    pub(crate) fn fixture() -> Content {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("content");
+        tree::load(&root, &Options::default()).unwrap().content
+    }
+
+    fn raw() -> Value {
+        serde_yaml::from_str(
+            "ru:\n  language: {name: Русский}\n  contact: {email: i@itmage.ru, phone: '+7'}\n  \
+             portfolio: {projects: [{name: Baza}, {name: Kubcoin}]}\n\
+             en:\n  language: {name: English, fallback: ru}\n  contact: {phone: '+1'}\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn looks_up_values_through_the_fallback() {
+        let raw = raw();
+        let text = |path| lookup(&raw, path).unwrap().as_str().unwrap();
+
+        assert_eq!(text("en.contact.phone"), "+1");
+        assert_eq!(text("en.contact.email"), "i@itmage.ru");
+        assert_eq!(text("ru.portfolio.projects[1].name"), "Kubcoin");
+        assert!(lookup(&raw, "ru.portfolio.projects[2].name").is_err());
+        assert!(lookup(&raw, "ru.portfolio.projects[x]").is_err());
+        assert!(lookup(&raw, "de.contact.email").is_err());
+    }
+}
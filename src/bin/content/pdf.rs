@@ -0,0 +1,424 @@
+use printpdf::{
+    Actions, BorderArray, Color, ColorArray, IndirectFontRef, Line, LinkAnnotation, Mm,
+    PdfDocument, PdfDocumentReference, PdfLayerReference, Point, Rect, Rgb,
+};
+use ttf_parser::Face;
+
+use itmagelab::content::{LangContent, Month};
+
+// Résumé of one language as an A4 PDF to attach to emails, laid out from the
+// same content as the `/:lang/cv` page. DejaVu Sans Condensed is embedded, so
+// Cyrillic renders the same in every viewer, and only the glyphs in use are
+// kept in the file.
+
+const REGULAR: &[u8] = include_bytes!("fonts/DejaVuSansCondensed.ttf");
+const BOLD: &[u8] = include_bytes!("fonts/DejaVuSansCondensed-Bold.ttf");
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+/// Width of the text column, in mm like every other length here
+const WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+/// Millimetres in a typographic point
+const PT: f32 = 25.4 / 72.0;
+/// Line height as a multiple of the font size
+const LEADING: f32 = 1.3;
+const BULLET_INDENT: f32 = 4.0;
+const SECTION_GAP: f32 = 4.0;
+const ENTRY_GAP: f32 = 2.0;
+
+#[derive(Clone, Copy)]
+struct Style {
+    bold: bool,
+    size: f32,
+    color: [f32; 3],
+}
+
+/// Tailwind's gray-800 and gray-600, as on the page
+const DARK: [f32; 3] = [0.122, 0.161, 0.216];
+const MUTED: [f32; 3] = [0.294, 0.333, 0.388];
+
+const NAME: Style = Style {
+    bold: true,
+    size: 20.0,
+    color: DARK,
+};
+const LABEL: Style = Style {
+    bold: false,
+    size: 11.0,
+    color: MUTED,
+};
+const HEADING: Style = Style {
+    bold: true,
+    size: 11.5,
+    color: DARK,
+};
+const TITLE: Style = Style {
+    bold: true,
+    size: 9.5,
+    color: DARK,
+};
+/// Dates and roles on the line of an entry title
+const ASIDE: Style = Style {
+    bold: false,
+    size: 9.5,
+    color: MUTED,
+};
+const BODY: Style = Style {
+    bold: false,
+    size: 9.0,
+    color: DARK,
+};
+const NOTE: Style = Style {
+    bold: false,
+    size: 8.5,
+    color: MUTED,
+};
+
+/// File name of the PDF for a language, e.g. `cv-en.pdf`
+pub fn file_name(lang: &str) -> String {
+    format!("cv-{lang}.pdf")
+}
+
+/// Text under an entry title
+enum Block {
+    Bullet(String),
+    Text(String),
+    Note(String),
+}
+
+/// Lay out the résumé, with durations of current positions counted up to `today`
+pub fn render(content: &LangContent, today: Month) -> Result<Vec<u8>, String> {
+    let (hero, about, portfolio, contact) = (
+        &content.hero,
+        &content.about,
+        &content.portfolio,
+        &content.contact,
+    );
+    let dates = &content.language.dates;
+    let mut pdf = Writer::new(&format!("{} — {}", hero.subtitle, about.cv.title))?;
+
+    pdf.paragraph(&hero.subtitle, NAME, 0.0);
+    pdf.paragraph(&hero.title, LABEL, 0.0);
+    pdf.y += ENTRY_GAP;
+    let links: Vec<(String, String)> = contact
+        .phone
+        .iter()
+        .map(|phone| {
+            let number = phone.replace(&[' ', '(', ')', '-'][..], "");
+            (phone.clone(), format!("tel:{number}"))
+        })
+        .chain(std::iter::once((
+            contact.email.clone(),
+            format!("mailto:{}", contact.email),
+        )))
+        .chain(contact.social_networks.iter().map(|network| {
+            let shown = network.url.trim_start_matches("https://").to_owned();
+            (shown, network.url.clone())
+        }))
+        .collect();
+    pdf.links(&links, NOTE);
+    pdf.y += ENTRY_GAP;
+    pdf.rule(0.8);
+
+    pdf.heading(&about.title);
+    pdf.paragraph(about.description.trim(), BODY, 0.0);
+    for item in &about.skills {
+        pdf.bullet(item, BODY);
+    }
+
+    pdf.heading(&about.work_experience_title);
+    for exp in &about.work_experience {
+        let period = format!(
+            "{} ({})",
+            dates.range(exp.start, exp.end),
+            dates.duration(exp.duration(today))
+        );
+        let blocks: Vec<Block> = exp
+            .description
+            .iter()
+            .map(|text| Block::Bullet(text.plain_text()))
+            .collect();
+        pdf.entry(
+            &format!("{} — {}", exp.position, exp.company),
+            &period,
+            &blocks,
+        );
+    }
+
+    pdf.heading(&portfolio.projects_title);
+    for project in &portfolio.projects {
+        let period = project
+            .period
+            .as_ref()
+            .map(|period| dates.range(period.start, period.end));
+        let meta: Vec<String> = project.role.iter().cloned().chain(period).collect();
+        let blocks: Vec<Block> = project
+            .description
+            .iter()
+            .map(|text| Block::Text(text.plain_text()))
+            .chain(std::iter::once(Block::Note(
+                project.technologies.join(", "),
+            )))
+            .collect();
+        pdf.entry(&project.name, &meta.join(" · "), &blocks);
+    }
+
+    pdf.heading(&portfolio.skills_title);
+    for (category, skills) in portfolio.skills_by_category() {
+        let skills: Vec<String> = skills
+            .iter()
+            .map(|skill| portfolio.skill_label(skill))
+            .collect();
+        pdf.entry(&category.name, "", &[Block::Text(skills.join(", "))]);
+    }
+
+    pdf.finish()
+}
+
+struct Font {
+    reference: IndirectFontRef,
+    face: Face<'static>,
+}
+
+/// Writes lines top to bottom, starting a new page when the next one does not fit
+struct Writer {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: Font,
+    bold: Font,
+    /// Distance of the next line from the top of the page
+    y: f32,
+}
+
+impl Writer {
+    fn new(title: &str) -> Result<Self, String> {
+        let (doc, page, layer) = PdfDocument::new(title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "cv");
+        let font = |data: &'static [u8]| -> Result<Font, String> {
+            Ok(Font {
+                reference: doc.add_external_font(data).map_err(|err| err.to_string())?,
+                face: Face::parse(data, 0).map_err(|err| err.to_string())?,
+            })
+        };
+        let (regular, bold) = (font(REGULAR)?, font(BOLD)?);
+        let layer = doc.get_page(page).get_layer(layer);
+        Ok(Writer {
+            doc,
+            layer,
+            regular,
+            bold,
+            y: MARGIN,
+        })
+    }
+
+    fn finish(self) -> Result<Vec<u8>, String> {
+        self.doc.save_to_bytes().map_err(|err| err.to_string())
+    }
+
+    fn font(&self, style: Style) -> &Font {
+        if style.bold {
+            &self.bold
+        } else {
+            &self.regular
+        }
+    }
+
+    /// Rendered width of a line, leaving out characters the font lacks as the PDF does
+    fn width(&self, text: &str, style: Style) -> f32 {
+        let face = &self.font(style).face;
+        let units: u32 = text
+            .chars()
+            .filter_map(|c| face.glyph_index(c))
+            .map(|glyph| u32::from(face.glyph_hor_advance(glyph).unwrap_or(0)))
+            .sum();
+        units as f32 / f32::from(face.units_per_em()) * style.size * PT
+    }
+
+    /// Break text into lines no wider than `width` at spaces, a longer word
+    /// keeping a line of its own
+    fn wrap(&self, text: &str, style: Style, width: f32) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        for word in text.split_whitespace() {
+            let longer = match line.as_str() {
+                "" => word.to_owned(),
+                line => format!("{line} {word}"),
+            };
+            if !line.is_empty() && self.width(&longer, style) > width {
+                lines.push(std::mem::replace(&mut line, word.to_owned()));
+            } else {
+                line = longer;
+            }
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+        lines
+    }
+
+    /// Start a new page unless `height` still fits on this one
+    fn keep(&mut self, height: f32) {
+        if self.y + height > PAGE_HEIGHT - MARGIN && self.y > MARGIN {
+            let (page, layer) = self.doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "cv");
+            self.layer = self.doc.get_page(page).get_layer(layer);
+            self.y = MARGIN;
+        }
+    }
+
+    /// Put text on the current line without moving down
+    fn text(&self, text: &str, style: Style, x: f32) {
+        let [r, g, b] = style.color;
+        self.layer
+            .set_fill_color(Color::Rgb(Rgb::new(r, g, b, None)));
+        let baseline = self.y + style.size * PT;
+        self.layer.use_text(
+            text,
+            style.size,
+            Mm(x),
+            Mm(PAGE_HEIGHT - baseline),
+            &self.font(style).reference,
+        );
+    }
+
+    fn paragraph(&mut self, text: &str, style: Style, indent: f32) {
+        for line in self.wrap(text, style, WIDTH - indent) {
+            self.keep(height(style));
+            self.text(&line, style, MARGIN + indent);
+            self.y += height(style);
+        }
+    }
+
+    fn bullet(&mut self, text: &str, style: Style) {
+        self.keep(height(style));
+        self.text("•", style, MARGIN + 1.0);
+        self.paragraph(text, style, BULLET_INDENT);
+    }
+
+    /// Horizontal line across the column, `thickness` in points
+    fn rule(&mut self, thickness: f32) {
+        let [r, g, b] = DARK;
+        self.layer
+            .set_outline_color(Color::Rgb(Rgb::new(r, g, b, None)));
+        self.layer.set_outline_thickness(thickness);
+        let y = Mm(PAGE_HEIGHT - self.y);
+        self.layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(MARGIN), y), false),
+                (Point::new(Mm(MARGIN + WIDTH), y), false),
+            ],
+            is_closed: false,
+        });
+    }
+
+    /// Section title, moved to the next page together with the start of the section
+    fn heading(&mut self, text: &str) {
+        self.y += SECTION_GAP;
+        self.keep(height(HEADING) + 3.0 * height(BODY));
+        self.text(&text.to_uppercase(), HEADING, MARGIN);
+        self.y += height(HEADING);
+        self.rule(0.6);
+        self.y += ENTRY_GAP;
+    }
+
+    /// Title with an aside on the right and the text under it, kept on one
+    /// page unless it is longer than a page
+    fn entry(&mut self, title: &str, aside: &str, blocks: &[Block]) {
+        let aside_width = match aside {
+            "" => 0.0,
+            aside => self.width(aside, ASIDE) + 4.0,
+        };
+        let titles = self.wrap(title, TITLE, WIDTH - aside_width);
+        let lines = |text: &str, style: Style, indent: f32| {
+            self.wrap(text, style, WIDTH - indent).len() as f32 * height(style)
+        };
+        let body: f32 = blocks
+            .iter()
+            .map(|block| match block {
+                Block::Bullet(text) => lines(text, BODY, BULLET_INDENT),
+                Block::Text(text) => lines(text, BODY, 0.0),
+                Block::Note(text) => lines(text, NOTE, 0.0),
+            })
+            .sum();
+
+        self.keep(titles.len() as f32 * height(TITLE) + body);
+        if !aside.is_empty() {
+            self.text(aside, ASIDE, MARGIN + WIDTH - self.width(aside, ASIDE));
+        }
+        for line in titles {
+            self.text(&line, TITLE, MARGIN);
+            self.y += height(TITLE);
+        }
+        for block in blocks {
+            match block {
+                Block::Bullet(text) => self.bullet(text, BODY),
+                Block::Text(text) => self.paragraph(text, BODY, 0.0),
+                Block::Note(text) => self.paragraph(text, NOTE, 0.0),
+            }
+        }
+        self.y += ENTRY_GAP;
+    }
+
+    /// Linked items separated by dots, wrapped like words
+    fn links(&mut self, links: &[(String, String)], style: Style) {
+        let separator = "  ·  ";
+        let mut x = MARGIN;
+        for (text, url) in links {
+            let width = self.width(text, style);
+            if x > MARGIN && x + self.width(separator, style) + width > MARGIN + WIDTH {
+                self.y += height(style);
+                x = MARGIN;
+            }
+            // Break the page before drawing anything of the line
+            self.keep(height(style));
+            if x > MARGIN {
+                self.text(separator, style, x);
+                x += self.width(separator, style);
+            }
+            self.text(text, style, x);
+            let rect = Rect::new(
+                Mm(x),
+                Mm(PAGE_HEIGHT - self.y - height(style)),
+                Mm(x + width),
+                Mm(PAGE_HEIGHT - self.y),
+            );
+            self.layer.add_link_annotation(LinkAnnotation::new(
+                rect,
+                Some(BorderArray::Solid([0.0, 0.0, 0.0])),
+                Some(ColorArray::Transparent),
+                Actions::uri(url.clone()),
+                None,
+            ));
+            x += width;
+        }
+        self.y += height(style);
+    }
+}
+
+fn height(style: Style) -> f32 {
+    style.size * PT * LEADING
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture;
+
+    #[test]
+    fn renders_every_language_with_font_subsets() {
+        let content = fixture();
+        for (lang, content) in content.languages() {
+            let pdf = render(content, Month::new(2025, 6).unwrap()).unwrap();
+            assert!(pdf.starts_with(b"%PDF-"), "{lang}");
+            // Both fonts in full take well over a megabyte
+            assert!(pdf.len() < 300_000, "{lang}: {} bytes", pdf.len());
+        }
+
+        let writer = Writer::new("cv").unwrap();
+        let text = "Инфраструктура на Kubernetes и Terraform для высоконагруженных сервисов";
+        let lines = writer.wrap(text, BODY, 40.0);
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| writer.width(line, BODY) <= 40.0));
+        assert_eq!(lines.join(" "), text);
+        assert!(writer.width("Опыт", BODY) > 0.0);
+    }
+}
@@ -0,0 +1,217 @@
+use serde::Serialize;
+
+use itmagelab::content::LangContent;
+
+// Export of one language's content in the JSON Resume format
+// (https://jsonresume.org/schema), for recruiting platforms and HR tools:
+// the hero and about sections become `basics`, positions become `work`,
+// and the portfolio gives `projects` and `skills`.
+
+#[derive(Serialize)]
+pub struct Resume {
+    basics: Basics,
+    work: Vec<Work>,
+    projects: Vec<Project>,
+    skills: Vec<Skill>,
+    meta: Meta,
+}
+
+#[derive(Serialize)]
+struct Basics {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    /// A URL, so a photo served from the site itself is left out: the
+    /// résumé does not know the address the site is published at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<String>,
+    summary: String,
+    profiles: Vec<Profile>,
+}
+
+#[derive(Serialize)]
+struct Profile {
+    network: String,
+    username: String,
+    url: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Work {
+    name: String,
+    position: String,
+    start_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_date: Option<String>,
+    highlights: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Project {
+    name: String,
+    description: String,
+    keywords: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    roles: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_date: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Skill {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level: Option<String>,
+    keywords: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct Meta {
+    language: String,
+}
+
+/// File name of the export for a language, e.g. `resume.en.json`
+pub fn file_name(lang: &str) -> String {
+    format!("resume.{lang}.json")
+}
+
+impl Resume {
+    pub fn new(lang: &str, content: &LangContent) -> Self {
+        let about = &content.about;
+        let contact = &content.contact;
+        let portfolio = &content.portfolio;
+
+        let basics = Basics {
+            // The hero title is the brand, the subtitle is the person's name
+            name: content.hero.subtitle.clone(),
+            label: about
+                .work_experience
+                .iter()
+                .find(|exp| exp.end.is_none())
+                .map(|exp| exp.position.clone()),
+            image: Some(about.photo.clone()).filter(|photo| photo.contains("://")),
+            email: contact.email.clone(),
+            phone: contact.phone.clone(),
+            summary: about.description.trim().to_owned(),
+            profiles: contact
+                .social_networks
+                .iter()
+                .map(|network| Profile {
+                    network: network.name.clone(),
+                    username: username(&network.url),
+                    url: network.url.clone(),
+                })
+                .collect(),
+        };
+
+        let work = about
+            .work_experience
+            .iter()
+            .map(|exp| Work {
+                name: exp.company.clone(),
+                position: exp.position.clone(),
+                start_date: exp.start.to_string(),
+                end_date: exp.end.map(|end| end.to_string()),
+                highlights: exp
+                    .description
+                    .iter()
+                    .map(|text| text.plain_text())
+                    .collect(),
+            })
+            .collect();
+
+        let projects = portfolio
+            .projects
+            .iter()
+            .map(|project| Project {
+                name: project.name.clone(),
+                description: project
+                    .description
+                    .iter()
+                    .map(|text| text.plain_text())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                keywords: project.technologies.clone(),
+                url: project.link.clone(),
+                roles: project.role.iter().cloned().collect(),
+                start_date: project.period.as_ref().map(|p| p.start.to_string()),
+                end_date: project
+                    .period
+                    .as_ref()
+                    .and_then(|p| p.end.map(|end| end.to_string())),
+            })
+            .collect();
+
+        let skills = portfolio
+            .skills
+            .iter()
+            .map(|skill| Skill {
+                name: skill.name.clone(),
+                level: skill
+                    .level
+                    .map(|level| portfolio.skill_levels.get(level).name.clone()),
+                keywords: skill.technologies.clone(),
+            })
+            .collect();
+
+        Resume {
+            basics,
+            work,
+            projects,
+            skills,
+            meta: Meta {
+                language: lang.to_owned(),
+            },
+        }
+    }
+}
+
+/// Last path segment of a profile URL, e.g. `itmagelab` for `https://t.me/itmagelab`
+fn username(url: &str) -> String {
+    url.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches('@')
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture;
+    use serde_json::Value;
+
+    #[test]
+    fn maps_content_onto_json_resume() {
+        let content = fixture();
+        let en = content.get(&"en".parse().unwrap()).unwrap();
+
+        let resume = serde_json::to_value(Resume::new("en", en)).unwrap();
+        assert_eq!(resume["basics"]["name"], "Andrey Semenov");
+        assert_eq!(resume["basics"]["profiles"][0]["username"], "itmagelab");
+        // The photo is a path on the site, not a URL
+        assert_eq!(resume["basics"].get("image"), None);
+        assert_eq!(resume["work"][0]["startDate"], "2022-02");
+        assert_eq!(resume["work"][0].get("endDate"), None);
+        assert_eq!(resume["work"][1]["endDate"], "2022-02");
+        assert_eq!(resume["meta"]["language"], "en");
+        let Value::Array(skills) = &resume["skills"] else {
+            panic!("skills is not a list");
+        };
+        assert_eq!(skills.len(), en.portfolio.skills.len());
+        // Unrated skills leave the level out rather than inventing one
+        for (skill, source) in skills.iter().zip(&en.portfolio.skills) {
+            assert_eq!(skill.get("level").is_some(), source.level.is_some());
+        }
+    }
+}
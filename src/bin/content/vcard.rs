@@ -0,0 +1,107 @@
+use qrcode::QrCode;
+use qrcode::render::svg;
+
+use itmagelab::content::ContactContent;
+
+// Contact card of one language, so visitors can save the phone, email and
+// profiles in one step, and a QR code of it to scan from a laptop screen.
+// vCard 3.0 is the version both Android and iOS contacts import.
+
+/// Longest line in octets before it is folded onto the next one
+const LINE_LENGTH: usize = 75;
+/// Module colours of the QR code, the page's gray-800 on white
+const DARK: &str = "#1f2937";
+const LIGHT: &str = "#ffffff";
+
+/// File name of the vCard for a language, e.g. `contact.en.vcf`
+pub fn file_name(lang: &str) -> String {
+    format!("contact.{lang}.vcf")
+}
+
+/// File name of the QR code of the vCard, e.g. `contact.en.svg`
+pub fn qr_file_name(lang: &str) -> String {
+    format!("contact.{lang}.svg")
+}
+
+/// vCard with `name` as the full name, the hero subtitle on the page
+pub fn new(name: &str, contact: &ContactContent) -> String {
+    let name = escape(name);
+    let mut lines = vec![
+        "BEGIN:VCARD".to_owned(),
+        "VERSION:3.0".to_owned(),
+        // The name is not split into parts, its order differs between languages
+        format!("N:{name};;;;"),
+        format!("FN:{name}"),
+    ];
+    if let Some(phone) = &contact.phone {
+        lines.push(format!("TEL;TYPE=CELL:{phone}"));
+    }
+    lines.push(format!("EMAIL;TYPE=INTERNET:{}", escape(&contact.email)));
+    for network in &contact.social_networks {
+        lines.push(format!("URL:{}", network.url));
+    }
+    lines.push("END:VCARD".to_owned());
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+/// QR code of a vCard as a standalone SVG
+pub fn qr(vcard: &str) -> Result<String, String> {
+    let code = QrCode::new(vcard.as_bytes()).map_err(|err| err.to_string())?;
+    let svg = code
+        .render::<svg::Color>()
+        .min_dimensions(256, 256)
+        .dark_color(svg::Color(DARK))
+        .light_color(svg::Color(LIGHT))
+        .build();
+    Ok(svg + "\n")
+}
+
+/// Escape the characters that separate vCard values
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+        .replace('\n', "\\n")
+}
+
+/// Split a long line into lines of at most `LINE_LENGTH` octets, each
+/// continuation starting with a space, without cutting a character in two
+fn fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > LINE_LENGTH {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture;
+
+    #[test]
+    fn builds_a_card_and_its_qr_code() {
+        let content = fixture();
+        let en = content.get(&"en".parse().unwrap()).unwrap();
+
+        let card = new(&en.hero.subtitle, &en.contact);
+        assert!(card.starts_with("BEGIN:VCARD\r\nVERSION:3.0\r\n"));
+        assert!(card.contains("\r\nFN:Andrey Semenov\r\n"));
+        assert!(card.contains("\r\nEMAIL;TYPE=INTERNET:i@itmage.ru\r\n"));
+        assert!(card.contains("\r\nURL:https://t.me/itmagelab\r\n"));
+        assert!(card.ends_with("\r\nEND:VCARD\r\n"));
+        assert!(qr(&card).unwrap().contains("<svg"));
+
+        assert_eq!(escape("Semenov; A, \\B"), "Semenov\\; A\\, \\\\B");
+        let long = format!("FN:{}", "Семенов ".repeat(12));
+        let folded = fold(&long);
+        assert!(folded.split("\r\n").all(|line| line.len() <= LINE_LENGTH));
+        assert_eq!(folded.replace("\r\n ", ""), long);
+    }
+}
@@ -1,6 +1,11 @@
 pub mod about_section;
 pub mod contact_section;
+pub mod draft_badge;
 pub mod footer_section;
+pub mod gallery;
 pub mod hero_section;
+pub mod icon;
 pub mod language_switcher;
+pub mod loading_screen;
 pub mod portfolio_section;
+pub mod rich_text;
@@ -1,15 +1,46 @@
+use wasm_bindgen_futures::JsFuture;
+use yew::platform::spawn_local;
 use yew::prelude::*;
+use yew_router::prelude::*;
 
-use crate::content::{get_about_content, AboutContent};
+use crate::Route;
+use crate::components::draft_badge::DraftBadge;
+use crate::components::icon::IconSvg;
+use crate::components::rich_text;
+use crate::content::date::total_experience;
+use crate::content::markdown;
+use crate::content::{
+    AboutContent, Draft, Icon, Lang, current_month, get_about_content, get_content,
+};
+use crate::pages::cv::PrintQuery;
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
-    pub language: String,
+    pub language: Lang,
 }
 
 #[function_component(AboutSection)]
 pub fn about_section(props: &Props) -> Html {
     let about_content: &AboutContent = get_about_content(&props.language);
+    let dates = &get_content(&props.language).language.dates;
+    let today = current_month();
+
+    let copied = use_state(|| false);
+    let copy_markdown = {
+        let language = props.language.clone();
+        let copied = copied.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(window) = web_sys::window() else {
+                return;
+            };
+            let text = markdown::cv(get_content(&language), current_month());
+            let promise = window.navigator().clipboard().write_text(&text);
+            let copied = copied.clone();
+            spawn_local(async move {
+                copied.set(JsFuture::from(promise).await.is_ok());
+            });
+        })
+    };
 
     html! {
         <section class="min-h-screen bg-white font-sansation py-16 px-4 flex items-center">
@@ -29,7 +60,7 @@ pub fn about_section(props: &Props) -> Html {
                                     about_content.skills.iter().map(|item| {
                                         html! {
                                             <li class="flex items-start gap-3">
-                                                <i class="fas fa-check-circle text-gray-800 text-xl mt-1 flex-shrink-0"></i>
+                                                <IconSvg icon={Icon::CheckCircle} class="text-gray-800 w-5 h-5 mt-1 flex-shrink-0" />
                                                 <span class="text-lg sm:text-xl text-gray-700">{ item }</span>
                                             </li>
                                         }
@@ -50,20 +81,29 @@ pub fn about_section(props: &Props) -> Html {
 
                     // Опыт работы по годам
                     <div class="border-t-2 border-gray-800 pt-8">
-                        <h3 class="text-3xl sm:text-4xl md:text-5xl font-bold mb-8 text-gray-800 text-center md:text-left">
+                        <h3 class="text-3xl sm:text-4xl md:text-5xl font-bold mb-2 text-gray-800 text-center md:text-left">
                             { &about_content.work_experience_title }
                         </h3>
+                        <p class="text-lg sm:text-xl text-gray-700 mb-8 font-semibold text-center md:text-left">
+                            { dates.duration(total_experience(&about_content.work_experience, today)) }
+                        </p>
                         <div class="space-y-6">
                             {
                                 about_content.work_experience.iter().map(|exp| {
                                     html! {
                                         <div class="flex flex-col sm:flex-row gap-4 sm:gap-6">
-                                            <div class="text-2xl sm:text-3xl font-bold text-gray-800 sm:w-32 flex-shrink-0 text-center sm:text-left">
-                                                { &exp.year }
+                                            <div class="sm:w-48 flex-shrink-0 text-center sm:text-left">
+                                                <div class="text-xl sm:text-2xl font-bold text-gray-800">
+                                                    { dates.range(exp.start, exp.end) }
+                                                </div>
+                                                <div class="text-base text-gray-600">
+                                                    { dates.duration(exp.duration(today)) }
+                                                </div>
                                             </div>
                                             <div class="flex-1">
                                                 <h4 class="text-xl sm:text-2xl font-bold text-gray-800 mb-2">
                                                     { &exp.position }
+                                                    <DraftBadge draft={exp.is_draft()} />
                                                 </h4>
                                                 <p class="text-lg sm:text-xl text-gray-700 mb-2 font-semibold">
                                                     { &exp.company }
@@ -72,7 +112,7 @@ pub fn about_section(props: &Props) -> Html {
                                                     {
                                                         exp.description.iter().map(|desc| {
                                                             html! {
-                                                                <li>{ Html::from_html_unchecked(AttrValue::from(desc.clone())) }</li>
+                                                                <li>{ rich_text::render(desc) }</li>
                                                             }
                                                         }).collect::<Html>()
                                                     }
@@ -84,9 +124,52 @@ pub fn about_section(props: &Props) -> Html {
                             }
                         </div>
                     </div>
+
+                    // Резюме для скачивания
+                    <div class="border-t-2 border-gray-800 pt-8 mt-8 flex flex-wrap items-center justify-center md:justify-start gap-4">
+                        <h3 class="text-2xl sm:text-3xl font-bold text-gray-800">
+                            { &about_content.cv.title }
+                        </h3>
+                        <a
+                            href={format!("/resume.{}.json", props.language)}
+                            download=""
+                            class="inline-flex items-center gap-2 bg-gray-800 text-green-300 font-semibold rounded-lg px-4 py-2 hover:bg-gray-700 transition-colors"
+                        >
+                            <IconSvg icon={Icon::Download} class="w-5 h-5" />
+                            { &about_content.cv.json_resume }
+                        </a>
+                        <a
+                            href={format!("/cv-{}.pdf", props.language)}
+                            download=""
+                            class="inline-flex items-center gap-2 bg-gray-800 text-green-300 font-semibold rounded-lg px-4 py-2 hover:bg-gray-700 transition-colors"
+                        >
+                            <IconSvg icon={Icon::Download} class="w-5 h-5" />
+                            { &about_content.cv.pdf }
+                        </a>
+                        <Link<Route, PrintQuery>
+                            to={Route::Cv { lang: props.language.clone() }}
+                            query={Some(PrintQuery { print: true })}
+                            classes="inline-flex items-center gap-2 bg-gray-800 text-green-300 font-semibold rounded-lg px-4 py-2 hover:bg-gray-700 transition-colors"
+                        >
+                            <IconSvg icon={Icon::Printer} class="w-5 h-5" />
+                            { &about_content.cv.print }
+                        </Link<Route, PrintQuery>>
+                        <button
+                            type="button"
+                            onclick={copy_markdown}
+                            class="inline-flex items-center gap-2 bg-gray-800 text-green-300 font-semibold rounded-lg px-4 py-2 hover:bg-gray-700 transition-colors"
+                        >
+                            if *copied {
+                                <IconSvg icon={Icon::CheckCircle} class="w-5 h-5" />
+                                { &about_content.cv.copied }
+                            } else {
+                                <IconSvg icon={Icon::Copy} class="w-5 h-5" />
+                                { &about_content.cv.markdown }
+                            }
+                        </button>
+                    </div>
                 </div>
             </div>
         </section>
     }
 }
-
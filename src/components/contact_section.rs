@@ -1,10 +1,11 @@
 use yew::prelude::*;
 
-use crate::content::{get_contact_content, ContactContent};
+use crate::components::icon::IconSvg;
+use crate::content::{ContactContent, Icon, Lang, get_contact_content};
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
-    pub language: String,
+    pub language: Lang,
 }
 
 #[function_component(ContactSection)]
@@ -19,27 +20,29 @@ pub fn contact_section(props: &Props) -> Html {
                 </h2>
 
                 // Контактная информация
-                <div class="grid grid-cols-1 md:grid-cols-2 gap-8 mb-16">
+                <div class={classes!("grid", "grid-cols-1", contact_content.phone.is_some().then_some("md:grid-cols-2"), "gap-8", "mb-16")}>
                     // Телефон
-                    <div class="bg-gray-900 rounded-lg p-8 border-2 border-green-300 hover:border-green-400 transition-colors">
-                        <div class="text-green-300 mb-4">
-                            <i class="fas fa-phone text-5xl"></i>
+                    if let Some(phone) = &contact_content.phone {
+                        <div class="bg-gray-900 rounded-lg p-8 border-2 border-green-300 hover:border-green-400 transition-colors">
+                            <div class="text-green-300 mb-4">
+                                <IconSvg icon={Icon::Phone} class="w-12 h-12 mx-auto" />
+                            </div>
+                            <h3 class="text-2xl font-bold text-green-300 mb-4">
+                                { &contact_content.phone_label }
+                            </h3>
+                            <a
+                                href={format!("tel:{}", phone.replace(&[' ', '(', ')', '-'][..], ""))}
+                                class="text-xl text-gray-300 hover:text-green-300 transition-colors"
+                            >
+                                { phone }
+                            </a>
                         </div>
-                        <h3 class="text-2xl font-bold text-green-300 mb-4">
-                            { &contact_content.phone_label }
-                        </h3>
-                        <a
-                            href={format!("tel:{}", contact_content.phone.replace(&[' ', '(', ')', '-'][..], ""))}
-                            class="text-xl text-gray-300 hover:text-green-300 transition-colors"
-                        >
-                            { &contact_content.phone }
-                        </a>
-                    </div>
+                    }
 
                     // Email
                     <div class="bg-gray-900 rounded-lg p-8 border-2 border-green-300 hover:border-green-400 transition-colors">
                         <div class="text-green-300 mb-4">
-                            <i class="fas fa-envelope text-5xl"></i>
+                            <IconSvg icon={Icon::Email} class="w-12 h-12 mx-auto" />
                         </div>
                         <h3 class="text-2xl font-bold text-green-300 mb-4">
                             { &contact_content.email_label }
@@ -53,6 +56,28 @@ pub fn contact_section(props: &Props) -> Html {
                     </div>
                 </div>
 
+                // Визитка: скачать vCard или отсканировать QR-код с экрана
+                <div class="flex flex-col md:flex-row items-center justify-center gap-8 mb-16">
+                    <img
+                        src={format!("/contact.{}.svg", props.language)}
+                        alt={contact_content.scan_contact.clone()}
+                        class="hidden md:block w-40 h-40 bg-white rounded-lg p-2 border-2 border-green-300"
+                    />
+                    <div class="flex flex-col items-center md:items-start gap-4">
+                        <p class="hidden md:block text-xl text-gray-300">
+                            { &contact_content.scan_contact }
+                        </p>
+                        <a
+                            href={format!("/contact.{}.vcf", props.language)}
+                            download=""
+                            class="inline-flex items-center gap-2 bg-green-300 text-gray-800 font-semibold text-lg rounded-lg px-6 py-3 hover:bg-green-400 transition-colors"
+                        >
+                            <IconSvg icon={Icon::Download} class="w-5 h-5" />
+                            { &contact_content.save_contact }
+                        </a>
+                    </div>
+                </div>
+
                 // Социальные сети
                 <div>
                     <h3 class="text-3xl font-bold text-green-300 mb-8">
@@ -68,7 +93,7 @@ pub fn contact_section(props: &Props) -> Html {
                                         rel="noopener noreferrer"
                                         class="bg-gray-900 rounded-lg p-6 border-2 border-green-300 hover:border-green-400 hover:bg-gray-700 transition-all flex flex-col items-center gap-3 min-w-[120px]"
                                     >
-                                        <i class={format!("{} text-5xl text-green-300", social.icon)}></i>
+                                        <IconSvg icon={social.icon} class="w-12 h-12 text-green-300" />
                                         <span class="text-lg font-semibold text-gray-300">{ &social.name }</span>
                                     </a>
                                 }
@@ -80,4 +105,3 @@ pub fn contact_section(props: &Props) -> Html {
         </section>
     }
 }
-
@@ -0,0 +1,20 @@
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub draft: bool,
+}
+
+/// Marker next to the title of a draft entry. Release builds strip drafts from
+/// the content, so it only ever shows on the dev server.
+#[function_component(DraftBadge)]
+pub fn draft_badge(props: &Props) -> Html {
+    if !props.draft {
+        return html! {};
+    }
+    html! {
+        <span class="ml-2 align-middle text-xs font-bold tracking-widest text-yellow-300 bg-gray-900 border-2 border-yellow-300 rounded px-2 py-0.5">
+            { "DRAFT" }
+        </span>
+    }
+}
@@ -1,10 +1,10 @@
 use yew::prelude::*;
 
-use crate::content::{get_footer_content, FooterContent};
+use crate::content::{FooterContent, Lang, get_footer_content};
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
-    pub language: String,
+    pub language: Lang,
 }
 
 #[function_component(FooterSection)]
@@ -24,4 +24,3 @@ pub fn footer_section(props: &Props) -> Html {
         </footer>
     }
 }
-
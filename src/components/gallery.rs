@@ -0,0 +1,188 @@
+use web_sys::{EventTarget, HtmlElement};
+use yew::prelude::*;
+
+use crate::components::icon::IconSvg;
+use crate::content::{GalleryLabels, Icon, Image};
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub images: &'static [Image],
+    pub labels: &'static GalleryLabels,
+}
+
+/// Screenshot thumbnails that open full size in a modal lightbox. The lightbox
+/// takes focus when it opens and keeps Tab among its buttons, closes on Escape
+/// or a click outside the image, pages with the arrow keys and hands focus back
+/// to its thumbnail on close.
+#[function_component(Gallery)]
+pub fn gallery(props: &Props) -> Html {
+    let open = use_state(|| None::<usize>);
+    let dialog = use_node_ref();
+    let close_button = use_node_ref();
+    let next_button = use_node_ref();
+    let thumbnails = use_memo(props.images.len(), |count| {
+        (0..*count).map(|_| NodeRef::default()).collect::<Vec<_>>()
+    });
+    let count = props.images.len();
+
+    {
+        let dialog = dialog.clone();
+        use_effect_with(open.is_some(), move |is_open| {
+            if *is_open && let Some(element) = dialog.cast::<HtmlElement>() {
+                let _ = element.focus();
+            }
+        });
+    }
+
+    let close = {
+        let open = open.clone();
+        let thumbnails = thumbnails.clone();
+        Callback::from(move |_: ()| {
+            let thumbnail = open.and_then(|index| thumbnails.get(index));
+            if let Some(element) = thumbnail.and_then(|node| node.cast::<HtmlElement>()) {
+                let _ = element.focus();
+            }
+            open.set(None);
+        })
+    };
+    // Step through the screenshots, wrapping around at either end
+    let step = {
+        let open = open.clone();
+        Callback::from(move |forward: bool| {
+            if let Some(index) = *open {
+                let offset = if forward { 1 } else { count - 1 };
+                open.set(Some((index + offset) % count));
+            }
+        })
+    };
+
+    // Tab past the last button goes back to the first and Shift+Tab the other
+    // way, also from the dialog itself, so focus never reaches the page behind
+    let trap_focus = {
+        let dialog = dialog.clone();
+        let first = close_button.clone();
+        let last = if count > 1 {
+            next_button.clone()
+        } else {
+            close_button.clone()
+        };
+        move |event: &KeyboardEvent| {
+            let target = event.target();
+            let is_target = |node: &NodeRef| node.get().map(EventTarget::from) == target;
+            let (from, to) = if event.shift_key() {
+                (&first, &last)
+            } else {
+                (&last, &first)
+            };
+            if (is_target(from) || is_target(&dialog))
+                && let Some(element) = to.cast::<HtmlElement>()
+            {
+                event.prevent_default();
+                let _ = element.focus();
+            }
+        }
+    };
+
+    let onkeydown = {
+        let close = close.clone();
+        let step = step.clone();
+        Callback::from(move |event: KeyboardEvent| match event.key().as_str() {
+            "Tab" => trap_focus(&event),
+            "Escape" => close.emit(()),
+            "ArrowLeft" => step.emit(false),
+            "ArrowRight" => step.emit(true),
+            _ => {}
+        })
+    };
+    let on_backdrop = {
+        let close = close.clone();
+        Callback::from(move |event: MouseEvent| {
+            if event.target() == event.current_target() {
+                close.emit(());
+            }
+        })
+    };
+
+    let button_class = "text-green-300 hover:text-green-400 bg-gray-800 rounded-full p-2 border-2 border-green-300 transition-colors";
+
+    html! {
+        <>
+            <div class="flex flex-wrap gap-3 mb-4">
+                {
+                    props.images.iter().zip(thumbnails.iter()).enumerate().map(|(index, (image, node))| {
+                        let open = open.clone();
+                        html! {
+                            <button
+                                type="button"
+                                ref={node.clone()}
+                                onclick={Callback::from(move |_| open.set(Some(index)))}
+                                aria-label={format!("{}: {}", props.labels.open, image.alt)}
+                                class="w-24 h-16 sm:w-32 sm:h-20 rounded border-2 border-gray-800 overflow-hidden hover:opacity-80 transition-opacity"
+                            >
+                                <img src={image.src.clone()} alt="" loading="lazy" class="w-full h-full object-cover" />
+                            </button>
+                        }
+                    }).collect::<Html>()
+                }
+            </div>
+            {
+                if let Some(image) = open.and_then(|index| props.images.get(index)) {
+                    let index = open.unwrap_or_default();
+                    html! {
+                        <div
+                            ref={dialog}
+                            role="dialog"
+                            aria-modal="true"
+                            aria-label={image.alt.clone()}
+                            tabindex="-1"
+                            onkeydown={onkeydown}
+                            onclick={on_backdrop}
+                            class="fixed inset-0 z-50 bg-gray-900/90 flex items-center justify-center p-4 outline-none"
+                        >
+                            <figure class="max-w-5xl flex flex-col items-center gap-3">
+                                <img
+                                    src={image.src.clone()}
+                                    alt={image.alt.clone()}
+                                    class="max-h-[80vh] w-auto rounded-lg border-2 border-green-300"
+                                />
+                                <figcaption class="text-lg text-green-300">
+                                    { format!("{} · {} / {count}", image.alt, index + 1) }
+                                </figcaption>
+                            </figure>
+                            <button
+                                type="button"
+                                ref={close_button}
+                                onclick={close.reform(|_| ())}
+                                aria-label={props.labels.close.clone()}
+                                class={classes!("absolute", "top-4", "right-4", button_class)}
+                            >
+                                <IconSvg icon={Icon::Close} class="w-6 h-6" />
+                            </button>
+                            if count > 1 {
+                                <button
+                                    type="button"
+                                    onclick={step.reform(|_| false)}
+                                    aria-label={props.labels.previous.clone()}
+                                    class={classes!("absolute", "left-4", "top-1/2", "-translate-y-1/2", button_class)}
+                                >
+                                    <IconSvg icon={Icon::ChevronLeft} class="w-6 h-6" />
+                                </button>
+                                <button
+                                    type="button"
+                                    ref={next_button}
+                                    onclick={step.reform(|_| true)}
+                                    aria-label={props.labels.next.clone()}
+                                    class={classes!("absolute", "right-4", "top-1/2", "-translate-y-1/2", button_class)}
+                                >
+                                    <IconSvg icon={Icon::ChevronRight} class="w-6 h-6" />
+                                </button>
+                            }
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+        </>
+    }
+}
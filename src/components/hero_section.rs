@@ -1,10 +1,11 @@
 use yew::prelude::*;
 
-use crate::content::{get_hero_content, HeroContent};
+use crate::components::icon::IconSvg;
+use crate::content::{HeroContent, Icon, Lang, get_hero_content};
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
-    pub language: String,
+    pub language: Lang,
 }
 
 #[function_component(HeroSection)]
@@ -15,8 +16,12 @@ pub fn hero_section(props: &Props) -> Html {
         <section class="bg-green-200 font-sansation py-6 px-4 shadow-md">
             <div class="flex flex-row items-center justify-between gap-6 w-full max-w-6xl mx-auto">
                 <div class="flex items-center gap-4">
-                    <div class="w-12 h-12 sm:w-16 sm:h-16 bg-gray-800 rounded-lg flex-shrink-0 flex items-center justify-center">
-                        <i class="fas fa-terminal text-green-300 text-2xl sm:text-3xl"></i>
+                    <div class="w-12 h-12 sm:w-16 sm:h-16 bg-gray-800 rounded-lg flex-shrink-0 flex items-center justify-center overflow-hidden">
+                        if let Some(logo) = &hero_content.logo {
+                            <img src={logo.clone()} alt="" class="w-full h-full object-contain" />
+                        } else {
+                            <IconSvg icon={Icon::Terminal} class="text-green-300 w-6 h-6 sm:w-8 sm:h-8" />
+                        }
                     </div>
                     <div class="text-left">
                         <h1 class="text-2xl sm:text-3xl md:text-4xl font-bold tracking-wider text-gray-800">
@@ -32,4 +37,3 @@ pub fn hero_section(props: &Props) -> Html {
         </section>
     }
 }
-
@@ -0,0 +1,126 @@
+use yew::prelude::*;
+
+use crate::content::Icon;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub icon: Icon,
+    /// Size and colour classes, the icon is drawn in `currentColor`
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Inline SVG icon on a 24×24 grid, decorative so hidden from screen readers
+#[function_component(IconSvg)]
+pub fn icon_svg(props: &Props) -> Html {
+    html! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            viewBox="0 0 24 24"
+            fill="none"
+            stroke="currentColor"
+            stroke-width="2"
+            stroke-linecap="round"
+            stroke-linejoin="round"
+            class={props.class.clone()}
+            aria-hidden="true"
+            focusable="false"
+        >
+            { shapes(props.icon) }
+        </svg>
+    }
+}
+
+fn shapes(icon: Icon) -> Html {
+    match icon {
+        Icon::CheckCircle => html! {
+            <>
+                <path d="M22 11.08V12a10 10 0 1 1-5.93-9.14" />
+                <polyline points="22 4 12 14.01 9 11.01" />
+            </>
+        },
+        Icon::ChevronLeft => html! { <polyline points="15 18 9 12 15 6" /> },
+        Icon::ChevronRight => html! { <polyline points="9 18 15 12 9 6" /> },
+        Icon::Close => html! {
+            <>
+                <line x1="18" y1="6" x2="6" y2="18" />
+                <line x1="6" y1="6" x2="18" y2="18" />
+            </>
+        },
+        Icon::Copy => html! {
+            <>
+                <rect x="9" y="9" width="13" height="13" rx="2" ry="2" />
+                <path d="M5 15H4a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h9a2 2 0 0 1 2 2v1" />
+            </>
+        },
+        Icon::Download => html! {
+            <>
+                <path d="M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4" />
+                <polyline points="7 10 12 15 17 10" />
+                <line x1="12" y1="15" x2="12" y2="3" />
+            </>
+        },
+        Icon::Email => html! {
+            <>
+                <path d="M4 4h16c1.1 0 2 .9 2 2v12c0 1.1-.9 2-2 2H4c-1.1 0-2-.9-2-2V6c0-1.1.9-2 2-2z" />
+                <polyline points="22,6 12,13 2,6" />
+            </>
+        },
+        Icon::ExternalLink => html! {
+            <>
+                <path d="M18 13v6a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2V8a2 2 0 0 1 2-2h6" />
+                <polyline points="15 3 21 3 21 9" />
+                <line x1="10" y1="14" x2="21" y2="3" />
+            </>
+        },
+        Icon::Github => html! {
+            <path d="M9 19c-5 1.5-5-2.5-7-3m14 6v-3.87a3.37 3.37 0 0 0-.94-2.61c3.14-.35 6.44-1.54 6.44-7A5.44 5.44 0 0 0 20 4.77 5.07 5.07 0 0 0 19.91 1S18.73.65 16 2.48a13.38 13.38 0 0 0-7 0C6.27.65 5.09 1 5.09 1A5.07 5.07 0 0 0 5 4.77a5.44 5.44 0 0 0-1.5 3.78c0 5.42 3.3 6.61 6.44 7A3.37 3.37 0 0 0 9 18.13V22" />
+        },
+        Icon::Globe => html! {
+            <>
+                <circle cx="12" cy="12" r="10" />
+                <line x1="2" y1="12" x2="22" y2="12" />
+                <path d="M12 2a15.3 15.3 0 0 1 4 10 15.3 15.3 0 0 1-4 10 15.3 15.3 0 0 1-4-10 15.3 15.3 0 0 1 4-10z" />
+            </>
+        },
+        Icon::Linkedin => html! {
+            <>
+                <path d="M16 8a6 6 0 0 1 6 6v7h-4v-7a2 2 0 0 0-2-2 2 2 0 0 0-2 2v7h-4v-7a6 6 0 0 1 6-6z" />
+                <rect x="2" y="9" width="4" height="12" />
+                <circle cx="4" cy="4" r="2" />
+            </>
+        },
+        Icon::Phone => html! {
+            <path d="M22 16.92v3a2 2 0 0 1-2.18 2 19.79 19.79 0 0 1-8.63-3.07 19.5 19.5 0 0 1-6-6 19.79 19.79 0 0 1-3.07-8.67A2 2 0 0 1 4.11 2h3a2 2 0 0 1 2 1.72 12.84 12.84 0 0 0 .7 2.81 2 2 0 0 1-.45 2.11L8.09 9.91a16 16 0 0 0 6 6l1.27-1.27a2 2 0 0 1 2.11-.45 12.84 12.84 0 0 0 2.81.7A2 2 0 0 1 22 16.92z" />
+        },
+        Icon::Printer => html! {
+            <>
+                <polyline points="6 9 6 2 18 2 18 9" />
+                <path d="M6 18H4a2 2 0 0 1-2-2v-5a2 2 0 0 1 2-2h16a2 2 0 0 1 2 2v5a2 2 0 0 1-2 2h-2" />
+                <rect x="6" y="14" width="12" height="8" />
+            </>
+        },
+        Icon::Telegram => html! {
+            <>
+                <line x1="22" y1="2" x2="11" y2="13" />
+                <polygon points="22 2 15 22 11 13 2 9 22 2" />
+            </>
+        },
+        Icon::Terminal => html! {
+            <>
+                <polyline points="4 17 10 11 4 5" />
+                <line x1="12" y1="19" x2="20" y2="19" />
+            </>
+        },
+        Icon::Vk => html! {
+            <>
+                <rect x="2" y="2" width="20" height="20" rx="5" />
+                <path
+                    d="M6 8.5c.2 4.5 2.6 7.5 6.3 7.5h.4v-2.6c1.4.1 2.4 1.2 2.8 2.6h1.9c-.5-1.9-1.8-3-2.6-3.4.8-.5 2-1.6 2.3-3.6h-1.7c-.4 1.5-1.5 2.8-2.7 2.9V8.5h-1.8v5.1c-1.2-.3-2.6-1.8-2.7-5.1z"
+                    fill="currentColor"
+                    stroke="none"
+                />
+            </>
+        },
+    }
+}
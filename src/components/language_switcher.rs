@@ -1,10 +1,12 @@
 use crate::Route;
+use crate::components::icon::IconSvg;
+use crate::content::{Icon, Lang, languages};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
-    pub current_lang: String,
+    pub current_lang: Lang,
 }
 
 #[function_component(LanguageSwitcher)]
@@ -19,24 +21,6 @@ pub fn language_switcher(props: &Props) -> Html {
         })
     };
 
-    let on_ru = {
-        let navigator = navigator.clone();
-        let is_open = is_open.clone();
-        Callback::from(move |_| {
-            navigator.push(&Route::Ru);
-            is_open.set(false);
-        })
-    };
-
-    let on_en = {
-        let navigator = navigator.clone();
-        let is_open = is_open.clone();
-        Callback::from(move |_| {
-            navigator.push(&Route::En);
-            is_open.set(false);
-        })
-    };
-
     html! {
         <div class="fixed top-4 right-4 z-50">
             // Toggle button
@@ -44,39 +28,43 @@ pub fn language_switcher(props: &Props) -> Html {
                 onclick={toggle_open}
                 class="bg-gray-800 text-green-300 px-4 py-2 rounded-lg shadow-lg font-semibold hover:bg-gray-700 transition-colors"
             >
-                { &props.current_lang.to_uppercase() }
-                <i class="fas fa-globe ml-2"></i>
+                { props.current_lang.as_str().to_uppercase() }
+                <IconSvg icon={Icon::Globe} class="inline-block w-4 h-4 ml-2 align-[-0.125em]" />
             </button>
 
             // Language options (shown when open)
             if *is_open {
                 <div class="absolute top-12 right-0 flex flex-col gap-2 bg-gray-800 rounded-lg p-2 shadow-lg">
-                    <button
-                        onclick={on_ru}
-                        class={format!(
-                            "px-4 py-2 rounded font-semibold transition-colors {}",
-                            if props.current_lang == "ru" {
-                                "bg-green-300 text-gray-800"
-                            } else {
-                                "bg-gray-700 text-green-300 hover:bg-gray-600"
-                            }
-                        )}
-                    >
-                        { "RU" }
-                    </button>
-                    <button
-                        onclick={on_en}
-                        class={format!(
-                            "px-4 py-2 rounded font-semibold transition-colors {}",
-                            if props.current_lang == "en" {
-                                "bg-green-300 text-gray-800"
-                            } else {
-                                "bg-gray-700 text-green-300 hover:bg-gray-600"
+                    {
+                        languages().map(|(lang, content)| {
+                            let on_select = {
+                                let navigator = navigator.clone();
+                                let is_open = is_open.clone();
+                                let lang = lang.clone();
+                                Callback::from(move |_| {
+                                    navigator.push(&Route::Home { lang: lang.clone() });
+                                    is_open.set(false);
+                                })
+                            };
+
+                            html! {
+                                <button
+                                    onclick={on_select}
+                                    lang={lang.to_string()}
+                                    class={format!(
+                                        "px-4 py-2 rounded font-semibold whitespace-nowrap transition-colors {}",
+                                        if *lang == props.current_lang {
+                                            "bg-green-300 text-gray-800"
+                                        } else {
+                                            "bg-gray-700 text-green-300 hover:bg-gray-600"
+                                        }
+                                    )}
+                                >
+                                    { &content.language.name }
+                                </button>
                             }
-                        )}
-                    >
-                        { "EN" }
-                    </button>
+                        }).collect::<Html>()
+                    }
                 </div>
             }
         </div>
@@ -0,0 +1,16 @@
+use yew::prelude::*;
+
+use crate::components::icon::IconSvg;
+use crate::content::Icon;
+
+/// Shown while the content file is being fetched, before any copy is available
+#[function_component(LoadingScreen)]
+pub fn loading_screen() -> Html {
+    html! {
+        <div class="min-h-screen bg-green-200 font-sansation flex items-center justify-center" role="status" aria-busy="true">
+            <div class="w-16 h-16 bg-gray-800 rounded-lg flex items-center justify-center">
+                <IconSvg icon={Icon::Terminal} class="text-green-300 w-8 h-8 animate-pulse" />
+            </div>
+        </div>
+    }
+}
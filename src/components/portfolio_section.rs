@@ -1,15 +1,70 @@
+use serde::{Deserialize, Serialize};
+use web_sys::Element;
 use yew::prelude::*;
+use yew_router::prelude::*;
 
-use crate::content::{get_portfolio_content, PortfolioContent};
+use crate::Route;
+use crate::components::draft_badge::DraftBadge;
+use crate::components::gallery::Gallery;
+use crate::components::icon::IconSvg;
+use crate::components::rich_text;
+use crate::content::{
+    DateLabels, Draft, Icon, Lang, PortfolioContent, Proficiency, Project, current_month,
+    filter::TechFilter, get_content, get_portfolio_content,
+};
+
+/// Query of a filtered portfolio, e.g. `#/en?tech=Terraform`
+#[derive(Serialize, Deserialize)]
+struct FilterQuery {
+    #[serde(default)]
+    tech: String,
+}
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
-    pub language: String,
+    pub language: Lang,
 }
 
 #[function_component(PortfolioSection)]
 pub fn portfolio_section(props: &Props) -> Html {
     let portfolio_content: &PortfolioContent = get_portfolio_content(&props.language);
+    let dates = &get_content(&props.language).language.dates;
+    let current_year = current_month().year() as u16;
+
+    let filter = use_location()
+        .and_then(|location| location.query::<FilterQuery>().ok())
+        .map(|query| TechFilter::from_query(&query.tech))
+        .unwrap_or_default();
+    let navigator = use_navigator();
+    let projects_ref = use_node_ref();
+
+    // Select technologies, or clear them when they are already selected, and
+    // keep the selection in the URL so the filtered view can be shared
+    let select = {
+        let lang = props.language.clone();
+        let filter = filter.clone();
+        let projects_ref = projects_ref.clone();
+        Callback::from(move |(technologies, scroll): (Vec<String>, bool)| {
+            let next = filter.toggle(&technologies);
+            if let Some(navigator) = &navigator {
+                let route = Route::Home { lang: lang.clone() };
+                if next.is_empty() {
+                    navigator.push(&route);
+                } else {
+                    let query = FilterQuery {
+                        tech: next.to_query(),
+                    };
+                    if let Err(err) = navigator.push_with_query(&route, &query) {
+                        gloo_console::error!(format!("Failed to update the filter: {err}"));
+                    }
+                }
+            }
+            if scroll && let Some(projects) = projects_ref.cast::<Element>() {
+                projects.scroll_into_view();
+            }
+        })
+    };
+    let projects = filter.projects(&portfolio_content.projects);
 
     html! {
         <section class="min-h-screen bg-green-200 font-sansation py-16 px-4 flex items-center">
@@ -20,18 +75,71 @@ pub fn portfolio_section(props: &Props) -> Html {
 
                 // Проекты
                 <div class="mb-16">
-                    <h3 class="text-3xl sm:text-4xl font-bold mb-8 text-gray-800">
+                    <h3 ref={projects_ref} class="text-3xl sm:text-4xl font-bold mb-8 text-gray-800">
                         { &portfolio_content.projects_title }
                     </h3>
+                    if !filter.is_empty() {
+                        <div class="flex flex-wrap items-center gap-3 mb-6" role="status">
+                            <span class="text-lg font-semibold text-gray-800">
+                                { format!("{}:", portfolio_content.filter.active) }
+                            </span>
+                            {
+                                filter.technologies().iter().map(|tech| {
+                                    html! {
+                                        <span class="px-3 py-1 bg-green-300 text-gray-800 border-2 border-gray-800 rounded-full text-sm font-semibold">
+                                            { tech }
+                                        </span>
+                                    }
+                                }).collect::<Html>()
+                            }
+                            <button
+                                type="button"
+                                onclick={select.reform(|_| (Vec::new(), false))}
+                                class="inline-flex items-center gap-1 text-gray-700 hover:text-gray-900 font-semibold underline"
+                            >
+                                <IconSvg icon={Icon::Close} class="w-4 h-4" />
+                                { &portfolio_content.filter.clear }
+                            </button>
+                        </div>
+                        if projects.is_empty() {
+                            <p class="text-lg text-gray-700">{ &portfolio_content.filter.empty }</p>
+                        }
+                    }
                     <div class="space-y-6">
                         {
-                            portfolio_content.projects.iter().map(|project| {
+                            projects.into_iter().map(|project| {
                                 html! {
                                     <div class="bg-white border-2 border-gray-800 rounded-lg p-6 shadow-lg">
+                                        {
+                                            if let Some(cover) = &project.cover {
+                                                html! {
+                                                    <img
+                                                        src={cover.src.clone()}
+                                                        alt={cover.alt.clone()}
+                                                        loading="lazy"
+                                                        class="w-full h-48 sm:h-64 object-cover rounded-lg border-2 border-gray-800 mb-6"
+                                                    />
+                                                }
+                                            } else {
+                                                html! {}
+                                            }
+                                        }
                                         <div class="flex flex-col sm:flex-row sm:items-start sm:justify-between gap-4 mb-4">
-                                            <h4 class="text-2xl font-bold text-gray-800">
-                                                { &project.name }
-                                            </h4>
+                                            <div>
+                                                <h4 class="text-2xl font-bold text-gray-800">
+                                                    { &project.name }
+                                                    <DraftBadge draft={project.is_draft()} />
+                                                </h4>
+                                                {
+                                                    if let Some(meta) = project_meta(project, dates) {
+                                                        html! {
+                                                            <p class="text-lg text-gray-600 font-semibold mt-1">{ meta }</p>
+                                                        }
+                                                    } else {
+                                                        html! {}
+                                                    }
+                                                }
+                                            </div>
                                             {
                                                 if let Some(link) = &project.link {
                                                     html! {
@@ -40,7 +148,7 @@ pub fn portfolio_section(props: &Props) -> Html {
                                                             target="_blank"
                                                             class="inline-flex items-center gap-2 text-gray-700 hover:text-gray-900 transition-colors"
                                                         >
-                                                            <i class="fas fa-external-link-alt"></i>
+                                                            <IconSvg icon={Icon::ExternalLink} class="w-4 h-4" />
                                                             <span class="text-sm font-semibold">{ "View Project" }</span>
                                                         </a>
                                                     }
@@ -53,18 +161,33 @@ pub fn portfolio_section(props: &Props) -> Html {
                                             {
                                                 project.description.iter().map(|desc| {
                                                     html! {
-                                                        <li>{ Html::from_html_unchecked(AttrValue::from(desc.clone())) }</li>
+                                                        <li>{ rich_text::render(desc) }</li>
                                                     }
                                                 }).collect::<Html>()
                                             }
                                         </ul>
+                                        if !project.screenshots.is_empty() {
+                                            <Gallery images={project.screenshots.as_slice()} labels={&portfolio_content.gallery} />
+                                        }
                                         <div class="flex flex-wrap gap-2">
                                             {
                                                 project.technologies.iter().map(|tech| {
+                                                    let selected = filter.contains(tech);
+                                                    let colors = if selected {
+                                                        "bg-green-300 text-gray-800 border-gray-800"
+                                                    } else {
+                                                        "bg-gray-800 text-green-300 border-gray-800 hover:bg-gray-700"
+                                                    };
+                                                    let technology = tech.clone();
                                                     html! {
-                                                        <span class="px-3 py-1 bg-gray-800 text-green-300 rounded-full text-sm font-semibold">
+                                                        <button
+                                                            type="button"
+                                                            aria-pressed={selected.to_string()}
+                                                            onclick={select.reform(move |_| (vec![technology.clone()], false))}
+                                                            class={classes!("px-3", "py-1", "border-2", "rounded-full", "text-sm", "font-semibold", "transition-colors", colors)}
+                                                        >
                                                             { tech }
-                                                        </span>
+                                                        </button>
                                                     }
                                                 }).collect::<Html>()
                                             }
@@ -81,33 +204,126 @@ pub fn portfolio_section(props: &Props) -> Html {
                     <h3 class="text-3xl sm:text-4xl font-bold mb-8 text-gray-800">
                         { &portfolio_content.skills_title }
                     </h3>
-                    <div class="grid grid-cols-1 md:grid-cols-2 gap-6">
+                    <div class="space-y-10">
                         {
-                            portfolio_content.skills.iter().map(|skill| {
+                            portfolio_content.skills_by_category().into_iter().map(|(category, skills)| {
                                 html! {
                                     <div>
-                                        <div class="flex justify-between items-center mb-2">
-                                            <span class="text-lg font-semibold text-gray-800">
-                                                { &skill.name }
-                                            </span>
-                                            <span class="text-lg font-bold text-gray-700">
-                                                { format!("{}%", skill.level) }
-                                            </span>
-                                        </div>
-                                        <div class="w-full bg-gray-300 rounded-full h-4 overflow-hidden border-2 border-gray-800">
-                                            <div
-                                                class="bg-gray-800 h-full transition-all duration-300"
-                                                style={format!("width: {}%", skill.level)}
-                                            ></div>
+                                        <h4 class="text-2xl font-bold mb-4 text-gray-800">
+                                            { &category.name }
+                                        </h4>
+                                        <div class="grid grid-cols-1 md:grid-cols-2 gap-6">
+                                            {
+                                                skills.into_iter().map(|skill| {
+                                                    let level = skill.level.map(|level| (level, portfolio_content.skill_levels.get(level)));
+                                                    let years = skill.duration(current_year).map(|duration| {
+                                                        let years = dates.duration(duration);
+                                                        match skill.last_used {
+                                                            Some(last_used) => format!("{years}, {} {last_used}", portfolio_content.last_used_label),
+                                                            None => years,
+                                                        }
+                                                    });
+                                                    let entry = html! {
+                                                        <>
+                                                            <div class="flex justify-between items-center gap-4 mb-2">
+                                                                <span class="text-lg font-semibold text-gray-800">
+                                                                    { &skill.name }
+                                                                    <DraftBadge draft={skill.is_draft()} />
+                                                                </span>
+                                                                if let Some((_, level)) = level {
+                                                                    <span class="text-lg font-bold text-gray-700 text-right" title={level.description.clone()}>
+                                                                        { &level.name }
+                                                                    </span>
+                                                                }
+                                                            </div>
+                                                            if let Some((proficiency, _)) = level {
+                                                                { level_scale(proficiency) }
+                                                            }
+                                                            if let Some(years) = years {
+                                                                <p class="text-sm text-gray-600 mt-1">{ years }</p>
+                                                            }
+                                                        </>
+                                                    };
+                                                    if skill.technologies.is_empty() {
+                                                        return html! { <div class="p-3">{ entry }</div> };
+                                                    }
+                                                    // Clicking a skill selects its technologies and scrolls up to the matching projects
+                                                    let highlighted = filter.highlights(skill);
+                                                    let technologies = skill.technologies.clone();
+                                                    html! {
+                                                        <button
+                                                            type="button"
+                                                            aria-pressed={highlighted.to_string()}
+                                                            onclick={select.reform(move |_| (technologies.clone(), true))}
+                                                            class={classes!(
+                                                                "block", "w-full", "text-left", "p-3", "rounded-lg", "border-2", "transition-colors",
+                                                                if highlighted { "bg-white border-gray-800" } else { "border-transparent hover:bg-green-100" }
+                                                            )}
+                                                        >
+                                                            { entry }
+                                                        </button>
+                                                    }
+                                                }).collect::<Html>()
+                                            }
                                         </div>
                                     </div>
                                 }
                             }).collect::<Html>()
                         }
                     </div>
+
+                    // Легенда уровней
+                    if portfolio_content.skills.iter().any(|skill| skill.level.is_some()) {
+                        <div class="mt-12 bg-white border-2 border-gray-800 rounded-lg p-6">
+                            <h4 class="text-xl font-bold mb-4 text-gray-800">
+                                { &portfolio_content.skill_levels.title }
+                            </h4>
+                            <dl class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                                {
+                                    Proficiency::ALL.into_iter().map(|level| {
+                                        let label = portfolio_content.skill_levels.get(level);
+                                        html! {
+                                            <div>
+                                                <dt class="flex items-center gap-3 text-lg font-semibold text-gray-800">
+                                                    <span class="w-24 flex-shrink-0">{ level_scale(level) }</span>
+                                                    { &label.name }
+                                                </dt>
+                                                <dd class="text-gray-700 mt-1">{ &label.description }</dd>
+                                            </div>
+                                        }
+                                    }).collect::<Html>()
+                                }
+                            </dl>
+                        </div>
+                    }
                 </div>
             </div>
         </section>
     }
 }
 
+/// Four segments filled up to the level, from beginner to expert
+fn level_scale(level: Proficiency) -> Html {
+    html! {
+        <div class="flex gap-1" aria-hidden="true">
+            {
+                Proficiency::ALL.into_iter().map(|step| {
+                    let fill = if step <= level { "bg-gray-800" } else { "bg-gray-300" };
+                    html! {
+                        <div class={classes!("flex-1", "h-4", "rounded-full", "border-2", "border-gray-800", fill)}></div>
+                    }
+                }).collect::<Html>()
+            }
+        </div>
+    }
+}
+
+/// Role and period of a project, e.g. `Author · Jan 2020 – Feb 2022`
+pub fn project_meta(project: &Project, dates: &DateLabels) -> Option<String> {
+    let period = project
+        .period
+        .as_ref()
+        .map(|period| dates.range(period.start, period.end));
+    let parts: Vec<String> = project.role.iter().cloned().chain(period).collect();
+    (!parts.is_empty()).then(|| parts.join(" · "))
+}
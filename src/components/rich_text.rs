@@ -0,0 +1,49 @@
+use yew::prelude::*;
+
+use crate::components::icon::IconSvg;
+use crate::content::Icon;
+use crate::content::rich_text::{Inline, RichText};
+
+/// Render Markdown description text into Yew nodes
+pub fn render(text: &RichText) -> Html {
+    render_inlines(&text.0)
+}
+
+fn render_inlines(nodes: &[Inline]) -> Html {
+    nodes.iter().map(render_inline).collect::<Html>()
+}
+
+fn render_inline(node: &Inline) -> Html {
+    match node {
+        Inline::Text(text) => html! { { text } },
+        Inline::Strong(children) => html! { <strong>{ render_inlines(children) }</strong> },
+        Inline::Emphasis(children) => html! { <em>{ render_inlines(children) }</em> },
+        Inline::Code(code) => html! {
+            <code class="px-1 bg-gray-200 rounded text-sm">{ code }</code>
+        },
+        Inline::Link { text, url } => html! {
+            <a
+                href={url.clone()}
+                target="_blank"
+                rel="noopener noreferrer"
+                class="underline hover:text-gray-900"
+            >
+                { text }
+            </a>
+        },
+        Inline::Chip { text, url } => html! {
+            <a
+                href={url.clone()}
+                target="_blank"
+                rel="noopener noreferrer"
+                class="inline-flex items-center px-1.5 py-0.5 bg-emerald-600 text-white text-sm rounded hover:bg-emerald-700 transition-colors font-bold no-underline shadow-sm"
+            >
+                <IconSvg icon={Icon::ExternalLink} class="w-3.5 h-3.5 mr-2" />
+                { text }
+            </a>
+        },
+        Inline::Html(sanitized) => {
+            Html::from_html_unchecked(AttrValue::from(sanitized.html.clone()))
+        }
+    }
+}
@@ -1,130 +1,170 @@
-use once_cell::sync::Lazy;
-use serde::Deserialize;
-
-// Embed the YAML file at compile time
-const CONTENT_YAML: &str = include_str!("../content/content.yaml");
-
-#[derive(Deserialize, Clone, PartialEq)]
-pub struct HeroContent {
-    pub title: String,
-    pub subtitle: String,
-    pub logo: String,
-}
-
-#[derive(Deserialize, Clone, PartialEq)]
-pub struct AboutContent {
-    pub photo: String,
-    pub title: String,
-    pub description: String,
-    pub skills: Vec<String>,
-    pub work_experience_title: String,
-    pub work_experience: Vec<WorkExperience>,
-}
-
-#[derive(Deserialize, Clone, PartialEq)]
-pub struct WorkExperience {
-    pub year: String,
-    pub position: String,
-    pub company: String,
-    pub description: Vec<String>,
-}
-
-#[derive(Deserialize, Clone, PartialEq)]
-pub struct PortfolioContent {
-    pub title: String,
-    pub projects_title: String,
-    pub skills_title: String,
-    pub projects: Vec<Project>,
-    pub skills: Vec<Skill>,
-}
-
-#[derive(Deserialize, Clone, PartialEq)]
-pub struct Project {
-    pub name: String,
-    pub description: Vec<String>,
-    pub link: Option<String>,
-    pub technologies: Vec<String>,
-}
-
-#[derive(Deserialize, Clone, PartialEq)]
-pub struct Skill {
-    pub name: String,
-    pub level: u8,
+pub mod date;
+pub mod draft;
+pub mod fallback;
+pub mod filter;
+pub mod icon;
+pub mod lang;
+pub mod links;
+pub mod markdown;
+pub mod model;
+pub mod overlay;
+pub mod parity;
+pub mod rich_text;
+pub mod sanitize;
+#[cfg(test)]
+pub mod schema;
+pub mod skills;
+pub mod tree;
+pub mod validate;
+pub mod variables;
+
+use gloo_net::http::Request;
+use once_cell::sync::OnceCell;
+
+use fallback::Substitution;
+
+pub use date::Month;
+pub use draft::Draft;
+pub use icon::Icon;
+pub use lang::Lang;
+pub use model::*;
+pub use skills::Proficiency;
+
+// Embed the content tree merged into one YAML document, build.rs has already validated it
+const CONTENT_YAML: &str = include_str!(concat!(env!("OUT_DIR"), "/content.yaml"));
+
+/// Embedded content as of June 2025, shared by the tests of the content modules
+#[cfg(test)]
+pub(crate) fn fixture() -> Content {
+    Content::from_yaml(CONTENT_YAML, Month::new(2025, 6).unwrap())
+        .expect("embedded content parses")
+        .0
+}
+
+/// Overlays applied to the embedded content, comma-separated, chosen with
+/// `CONTENT_OVERLAY` at build time
+pub const OVERLAYS: &str = env!("CONTENT_OVERLAYS");
+
+/// URL of a content file to fetch at startup instead of using the embedded copy,
+/// set with the `CONTENT_URL` environment variable at build time
+pub fn content_url() -> Option<&'static str> {
+    option_env!("CONTENT_URL").filter(|url| !url.is_empty())
+}
+
+// Content in use, either fetched from `CONTENT_URL` or parsed from the embedded copy
+static CONTENT: OnceCell<Content> = OnceCell::new();
+
+fn content() -> &'static Content {
+    CONTENT.get_or_init(|| {
+        // build.rs has parsed this very document, and the month only changes
+        // computed values such as years of experience, so this should never
+        // fail. If it does, the site still opens, empty, with the error logged.
+        let (content, substitutions) = Content::from_yaml(CONTENT_YAML, current_month())
+            .unwrap_or_else(|err| {
+                gloo_console::error!(format!("Failed to parse embedded content: {err}"));
+                (Content::default(), Vec::new())
+            });
+        report(&content, &substitutions);
+        content
+    })
+}
+
+/// Fetch the content file from `url` and use it for the rest of the session.
+/// The embedded copy stays in use if the request or the parse fails.
+pub async fn load_remote(url: &str) {
+    match fetch(url).await {
+        Ok(content) => {
+            if CONTENT.set(content).is_err() {
+                gloo_console::warn!("Content was used before it loaded, keeping the embedded copy");
+            }
+        }
+        Err(message) => {
+            gloo_console::error!(format!("Using embedded content: {message}"));
+        }
+    }
 }
 
-#[derive(Deserialize, Clone, PartialEq)]
-pub struct ContactContent {
-    pub title: String,
-    pub phone: String,
-    pub phone_label: String,
-    pub email: String,
-    pub email_label: String,
-    pub social_title: String,
-    pub social_networks: Vec<SocialNetwork>,
+/// Fetch and parse a YAML or JSON content file, JSON being a subset of YAML
+async fn fetch(url: &str) -> Result<Content, String> {
+    let response = Request::get(url)
+        .send()
+        .await
+        .map_err(|err| format!("{url}: {err}"))?;
+    if !response.ok() {
+        return Err(format!("{url}: HTTP {}", response.status()));
+    }
+    let source = response
+        .text()
+        .await
+        .map_err(|err| format!("{url}: {err}"))?;
+    let (content, substitutions) =
+        Content::from_yaml(&source, current_month()).map_err(|err| format!("{url}: {err}"))?;
+    report(&content, &substitutions);
+    Ok(content)
+}
+
+/// Log content problems to the browser console in debug builds
+fn report(content: &Content, substitutions: &[Substitution]) {
+    if cfg!(debug_assertions) {
+        for substitution in substitutions {
+            gloo_console::warn!(format!(
+                "Missing translation `{}`, using `{}`",
+                substitution.path, substitution.from
+            ));
+        }
+        for stripped in content.sanitizer_report() {
+            gloo_console::warn!(format!("Sanitized {stripped}"));
+        }
+    }
 }
 
-#[derive(Deserialize, Clone, PartialEq)]
-pub struct SocialNetwork {
-    pub name: String,
-    pub url: String,
-    pub icon: String,
+/// Get the current month from the browser clock
+pub fn current_month() -> Month {
+    let now = js_sys::Date::new_0();
+    Month::clamped(now.get_full_year() as u16, now.get_month() as u8 + 1)
 }
 
-#[derive(Deserialize, Clone, PartialEq)]
-pub struct FooterContent {
-    pub copyright: String,
-    pub credits: String,
+/// Iterate over all languages declared in the content file
+pub fn languages() -> impl Iterator<Item = (&'static Lang, &'static LangContent)> {
+    content().languages()
 }
 
-#[derive(Deserialize, Clone, PartialEq)]
-pub struct LangContent {
-    pub hero: HeroContent,
-    pub about: AboutContent,
-    pub portfolio: PortfolioContent,
-    pub contact: ContactContent,
-    pub footer: FooterContent,
+/// Get the language the site opens with
+pub fn default_lang() -> &'static Lang {
+    content().default_lang()
 }
 
-#[derive(Deserialize, Clone, PartialEq)]
-pub struct Content {
-    pub ru: LangContent,
-    pub en: LangContent,
+/// Check whether the content file declares the specified language
+pub fn has_lang(lang: &Lang) -> bool {
+    content().get(lang).is_some()
 }
 
-// Parse YAML once and cache it
-static PARSED_CONTENT: Lazy<Content> = Lazy::new(|| {
-    serde_yaml::from_str(CONTENT_YAML).expect("Failed to parse embedded content.yaml")
-});
-
-/// Get content for the specified language
-pub fn get_content(lang: &str) -> &'static LangContent {
-    match lang {
-        "en" => &PARSED_CONTENT.en,
-        _ => &PARSED_CONTENT.ru,
-    }
+/// Get content for the specified language, or the default language if it is missing
+pub fn get_content(lang: &Lang) -> &'static LangContent {
+    content().get_or_default(lang)
 }
 
 /// Get hero content for the specified language
-pub fn get_hero_content(lang: &str) -> &'static HeroContent {
+pub fn get_hero_content(lang: &Lang) -> &'static HeroContent {
     &get_content(lang).hero
 }
 
 /// Get about content for the specified language
-pub fn get_about_content(lang: &str) -> &'static AboutContent {
+pub fn get_about_content(lang: &Lang) -> &'static AboutContent {
     &get_content(lang).about
 }
 
 /// Get portfolio content for the specified language
-pub fn get_portfolio_content(lang: &str) -> &'static PortfolioContent {
+pub fn get_portfolio_content(lang: &Lang) -> &'static PortfolioContent {
     &get_content(lang).portfolio
 }
 
 /// Get contact content for the specified language
-pub fn get_contact_content(lang: &str) -> &'static ContactContent {
+pub fn get_contact_content(lang: &Lang) -> &'static ContactContent {
     &get_content(lang).contact
 }
 
 /// Get footer content for the specified language
-pub fn get_footer_content(lang: &str) -> &'static FooterContent {
+pub fn get_footer_content(lang: &Lang) -> &'static FooterContent {
     &get_content(lang).footer
 }
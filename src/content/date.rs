@@ -0,0 +1,303 @@
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use std::fmt;
+use std::str::FromStr;
+
+use super::model::{DateLabels, WorkExperience};
+
+/// A calendar month written as `YYYY-MM` in the content file
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Month(u32);
+
+impl Month {
+    pub fn new(year: u16, month: u8) -> Option<Self> {
+        (1..=12)
+            .contains(&month)
+            .then(|| Self(u32::from(year) * 12 + u32::from(month) - 1))
+    }
+
+    /// Like `new`, with a month outside 1 to 12 moved to the nearest end
+    pub fn clamped(year: u16, month: u8) -> Self {
+        Self(u32::from(year) * 12 + u32::from(month.clamp(1, 12)) - 1)
+    }
+
+    pub fn year(self) -> u32 {
+        self.0 / 12
+    }
+
+    /// Month of the year, 1 to 12
+    pub fn month(self) -> u32 {
+        self.0 % 12 + 1
+    }
+
+    /// The month containing the given day, counted from 1970-01-01
+    pub fn from_unix_days(days: i64) -> Option<Self> {
+        // Civil date from day number, see http://howardhinnant.github.io/date_algorithms.html
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Month::new(year.try_into().ok()?, month.try_into().ok()?)
+    }
+
+    /// Number of months from `self` to `end`, counting both ends
+    pub fn months_through(self, end: Month) -> u32 {
+        (end.0 + 1).saturating_sub(self.0)
+    }
+}
+
+impl FromStr for Month {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid month `{value}`, expected YYYY-MM");
+        let (year, month) = value.split_once('-').ok_or_else(invalid)?;
+        if year.len() != 4 || month.len() != 2 {
+            return Err(invalid());
+        }
+        let year = year.parse().map_err(|_| invalid())?;
+        let month = month.parse().map_err(|_| invalid())?;
+        Month::new(year, month).ok_or_else(invalid)
+    }
+}
+
+impl fmt::Display for Month {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year(), self.month())
+    }
+}
+
+impl<'de> Deserialize<'de> for Month {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MonthVisitor;
+
+        impl Visitor<'_> for MonthVisitor {
+            type Value = Month;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a month such as `2022-02`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Month, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(MonthVisitor)
+    }
+}
+
+#[cfg(test)]
+impl schemars::JsonSchema for Month {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Month".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "A month written as `YYYY-MM`, e.g. `2022-02`",
+            "type": "string",
+            "pattern": "^[0-9]{4}-(0[1-9]|1[0-2])$",
+        })
+    }
+}
+
+/// Length of a period in whole years and remaining months
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Duration {
+    pub years: u32,
+    pub months: u32,
+}
+
+impl Duration {
+    pub fn from_months(total: u32) -> Self {
+        Self {
+            years: total / 12,
+            months: total % 12,
+        }
+    }
+}
+
+impl WorkExperience {
+    /// Duration of the position, with an open end counted up to `today`
+    pub fn duration(&self, today: Month) -> Duration {
+        Duration::from_months(self.start.months_through(self.end.unwrap_or(today)))
+    }
+}
+
+/// Total time covered by all positions, counting overlapping months once
+pub fn total_experience(work_experience: &[WorkExperience], today: Month) -> Duration {
+    let mut periods: Vec<(Month, Month)> = work_experience
+        .iter()
+        .map(|exp| (exp.start, exp.end.unwrap_or(today)))
+        .collect();
+    periods.sort();
+
+    let mut total = 0;
+    let mut covered_until: Option<Month> = None;
+    for (start, end) in periods {
+        let start = match covered_until {
+            Some(covered) if covered >= end => continue,
+            Some(covered) if covered >= start => Month(covered.0 + 1),
+            _ => start,
+        };
+        total += start.months_through(end);
+        covered_until = Some(end);
+    }
+    Duration::from_months(total)
+}
+
+/// English labels, so placeholder content still formats its dates
+impl Default for DateLabels {
+    fn default() -> Self {
+        let words = |words: &[&str]| words.iter().map(|word| (*word).to_owned()).collect();
+        Self {
+            present: "present".to_owned(),
+            month_names: words(&[
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ]),
+            years: words(&["yr", "yrs"]),
+            months: words(&["mo", "mos"]),
+        }
+    }
+}
+
+impl DateLabels {
+    /// Format a month as its short localized name and year, e.g. `Feb 2022`
+    pub fn month(&self, month: Month) -> String {
+        let index = month.month() as usize - 1;
+        format!("{} {}", self.month_names[index], month.year())
+    }
+
+    /// Format a period such as `Jan 2020 – Feb 2022`, or `Feb 2022 – present`
+    pub fn range(&self, start: Month, end: Option<Month>) -> String {
+        let end = match end {
+            Some(end) => self.month(end),
+            None => self.present.clone(),
+        };
+        format!("{} – {end}", self.month(start))
+    }
+
+    /// Format a duration such as `3 yrs 8 mos` or `3 года 8 месяцев`
+    pub fn duration(&self, duration: Duration) -> String {
+        let mut parts = Vec::new();
+        if duration.years > 0 {
+            parts.push(format!(
+                "{} {}",
+                duration.years,
+                plural(&self.years, duration.years)
+            ));
+        }
+        if duration.months > 0 || duration.years == 0 {
+            parts.push(format!(
+                "{} {}",
+                duration.months,
+                plural(&self.months, duration.months)
+            ));
+        }
+        parts.join(" ")
+    }
+}
+
+/// Pick a plural form: three forms follow the East Slavic rule
+/// (`год`, `года`, `лет`), two forms are singular and plural, one is invariant
+fn plural(forms: &[String], count: u32) -> &str {
+    let index = match forms.len() {
+        3 => match (count % 10, count % 100) {
+            (1, rem) if rem != 11 => 0,
+            (2..=4, rem) if !(12..=14).contains(&rem) => 1,
+            _ => 2,
+        },
+        2 => usize::from(count != 1),
+        _ => 0,
+    };
+    &forms[index]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn month(value: &str) -> Month {
+        value.parse().unwrap()
+    }
+
+    fn labels(years: &[&str], months: &[&str]) -> DateLabels {
+        DateLabels {
+            present: "present".to_owned(),
+            month_names: (1..=12).map(|m| format!("m{m}")).collect(),
+            years: years.iter().map(|s| s.to_string()).collect(),
+            months: months.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn experience(start: &str, end: Option<&str>) -> WorkExperience {
+        WorkExperience {
+            start: month(start),
+            end: end.map(month),
+            position: "Engineer".to_owned(),
+            company: "Company".to_owned(),
+            description: Vec::new(),
+            draft: false,
+            visible: true,
+        }
+    }
+
+    #[test]
+    fn parses_only_year_month() {
+        assert_eq!(month("2022-02"), Month::new(2022, 2).unwrap());
+        assert!("02.2022".parse::<Month>().is_err());
+        assert!("2022-13".parse::<Month>().is_err());
+        assert_eq!(Month::from_unix_days(0), Month::new(1970, 1));
+        assert_eq!(Month::from_unix_days(19_782), Month::new(2024, 2));
+        assert_eq!(Month::from_unix_days(19_783), Month::new(2024, 3));
+        assert_eq!(Month::clamped(2024, 0), month("2024-01"));
+        assert_eq!(Month::clamped(2024, 13), month("2024-12"));
+    }
+
+    #[test]
+    fn placeholder_labels_format_dates() {
+        let labels = DateLabels::default();
+        assert_eq!(labels.range(month("2022-02"), None), "Feb 2022 – present");
+        assert_eq!(labels.duration(Duration::from_months(13)), "1 yr 1 mo");
+    }
+
+    #[test]
+    fn formats_localized_durations() {
+        let ru = labels(&["год", "года", "лет"], &["месяц", "месяца", "месяцев"]);
+        let en = labels(&["yr", "yrs"], &["mo", "mos"]);
+        let duration = Duration::from_months(3 * 12 + 8);
+
+        assert_eq!(ru.duration(duration), "3 года 8 месяцев");
+        assert_eq!(en.duration(duration), "3 yrs 8 mos");
+        assert_eq!(
+            ru.duration(Duration::from_months(11 * 12 + 1)),
+            "11 лет 1 месяц"
+        );
+        assert_eq!(en.duration(Duration::from_months(12)), "1 yr");
+    }
+
+    #[test]
+    fn total_experience_counts_overlaps_once() {
+        let today = month("2024-12");
+        let work = [
+            experience("2022-01", None),
+            experience("2020-01", Some("2022-06")),
+            experience("2010-01", Some("2010-12")),
+        ];
+
+        assert_eq!(work[0].duration(today), Duration::from_months(36));
+        assert_eq!(
+            total_experience(&work, today),
+            Duration::from_months(60 + 12)
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use serde_yaml::Value;
+
+use super::model::{Project, Skill, WorkExperience};
+
+// Projects, positions and skills can be staged with `draft: true` or
+// `visible: false`. Debug builds show them with a draft marker so they can be
+// reviewed on the dev server, release builds drop them from the merged
+// document before it is embedded or published.
+
+/// Lists whose entries can be drafts, as section and field
+pub const LISTS: &[(&str, &str)] = &[
+    ("about", "work_experience"),
+    ("portfolio", "projects"),
+    ("portfolio", "skills"),
+];
+
+/// Content item that can be staged before it is published
+pub trait Draft {
+    fn is_draft(&self) -> bool;
+}
+
+impl Draft for Project {
+    fn is_draft(&self) -> bool {
+        self.draft || !self.visible
+    }
+}
+
+impl Draft for WorkExperience {
+    fn is_draft(&self) -> bool {
+        self.draft || !self.visible
+    }
+}
+
+impl Draft for Skill {
+    fn is_draft(&self) -> bool {
+        self.draft || !self.visible
+    }
+}
+
+/// Remove draft entries from the raw document with every language and tell
+/// whether there were any. Translations fill missing fields from the same
+/// position in the fallback language, so an entry that is a draft in one
+/// language is removed from all of them to keep positions aligned. Skills stop
+/// naming technologies that only draft projects used, which would otherwise
+/// select no project and fail the skill check.
+pub fn strip(raw: &mut Value) -> bool {
+    let drafted = technologies(raw);
+    let stripped = strip_lists(raw);
+    if stripped {
+        let published = technologies(raw);
+        let dropped: Vec<&String> = drafted
+            .iter()
+            .filter(|technology| !published.contains(technology))
+            .collect();
+        prune_skill_technologies(raw, &dropped);
+    }
+    stripped
+}
+
+fn strip_lists(raw: &mut Value) -> bool {
+    let Value::Mapping(languages) = raw else {
+        return false;
+    };
+    let mut stripped = false;
+    for (section, field) in LISTS {
+        let mut drafts: Vec<usize> = languages
+            .values()
+            .filter_map(|content| content.get(section)?.get(field)?.as_sequence())
+            .flat_map(|items| {
+                items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| is_draft(item))
+                    .map(|(index, _)| index)
+            })
+            .collect();
+        drafts.sort_unstable();
+        drafts.dedup();
+
+        for content in languages.values_mut() {
+            let items = content
+                .get_mut(section)
+                .and_then(|section| section.get_mut(field))
+                .and_then(Value::as_sequence_mut);
+            let Some(items) = items else {
+                continue;
+            };
+            let count = items.len();
+            let mut index = 0;
+            items.retain(|_| {
+                index += 1;
+                drafts.binary_search(&(index - 1)).is_err()
+            });
+            stripped |= items.len() != count;
+        }
+    }
+    stripped
+}
+
+/// Technologies of the projects in every language, lowercase as the skill
+/// check compares them
+fn technologies(raw: &Value) -> Vec<String> {
+    let Value::Mapping(languages) = raw else {
+        return Vec::new();
+    };
+    let mut technologies: Vec<String> = languages
+        .values()
+        .filter_map(|content| content.get("portfolio")?.get("projects")?.as_sequence())
+        .flatten()
+        .filter_map(|project| project.get("technologies")?.as_sequence())
+        .flatten()
+        .filter_map(Value::as_str)
+        .map(str::to_ascii_lowercase)
+        .collect();
+    technologies.sort_unstable();
+    technologies.dedup();
+    technologies
+}
+
+fn prune_skill_technologies(raw: &mut Value, dropped: &[&String]) {
+    let Value::Mapping(languages) = raw else {
+        return;
+    };
+    let skills = languages
+        .values_mut()
+        .filter_map(|content| {
+            content
+                .get_mut("portfolio")?
+                .get_mut("skills")?
+                .as_sequence_mut()
+        })
+        .flatten();
+    for skill in skills {
+        if let Some(technologies) = skill
+            .get_mut("technologies")
+            .and_then(Value::as_sequence_mut)
+        {
+            technologies.retain(|technology| {
+                technology
+                    .as_str()
+                    .is_none_or(|name| !dropped.contains(&&name.to_ascii_lowercase()))
+            });
+        }
+    }
+}
+
+fn is_draft(item: &Value) -> bool {
+    item.get("draft").and_then(Value::as_bool) == Some(true)
+        || item.get("visible").and_then(Value::as_bool) == Some(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{CONTENT_YAML, Content, Month};
+
+    #[test]
+    fn strips_drafts_from_every_language() {
+        let today = Month::new(2025, 6).unwrap();
+        let mut raw: Value = serde_yaml::from_str(CONTENT_YAML).unwrap();
+        assert!(!strip(&mut raw));
+
+        raw["ru"]["portfolio"]["skills"][1]["draft"] = Value::from(true);
+        raw["en"]["about"]["work_experience"][0]["visible"] = Value::from(false);
+        let merged = serde_yaml::to_string(&raw).unwrap();
+        let (with_drafts, _) = Content::from_yaml(&merged, today).unwrap();
+        for (_, content) in with_drafts.languages() {
+            assert!(content.portfolio.skills[1].is_draft());
+        }
+
+        assert!(strip(&mut raw));
+        let merged = serde_yaml::to_string(&raw).unwrap();
+        let (content, _) = Content::from_yaml(&merged, today).unwrap();
+        for (lang, content) in content.languages() {
+            let full = with_drafts.get(lang).unwrap();
+            let skills = &content.portfolio.skills;
+            assert_eq!(skills.len(), full.portfolio.skills.len() - 1);
+            assert!(skills.iter().all(|skill| !skill.is_draft()));
+            let work = &content.about.work_experience;
+            assert_eq!(work.len(), full.about.work_experience.len() - 1);
+        }
+    }
+}
@@ -0,0 +1,416 @@
+use serde::de::{
+    self, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde_yaml::Value;
+use std::cell::RefCell;
+use std::fmt;
+
+use super::lang::Lang;
+
+// A secondary language is deserialized straight from the YAML source, so
+// values it does define keep their line and column in errors. Struct fields
+// and list entries it leaves out are supplied from the primary language's
+// `Value` and recorded as substitutions.
+
+/// A value taken from the fallback language because the translation lacks it
+#[derive(Clone, PartialEq, Debug)]
+pub struct Substitution {
+    pub path: String,
+    pub from: Lang,
+}
+
+/// The fallback subtree matching the node currently being deserialized
+#[derive(Clone)]
+pub struct Fallback<'a> {
+    value: &'a Value,
+    path: String,
+    from: Lang,
+    log: &'a RefCell<Vec<Substitution>>,
+}
+
+impl<'a> Fallback<'a> {
+    pub fn new(
+        value: &'a Value,
+        path: String,
+        from: Lang,
+        log: &'a RefCell<Vec<Substitution>>,
+    ) -> Self {
+        Self {
+            value,
+            path,
+            from,
+            log,
+        }
+    }
+
+    fn field(&self, key: &str) -> Option<Self> {
+        self.value.get(key).map(|value| Self {
+            value,
+            path: format!("{}.{key}", self.path),
+            ..self.clone()
+        })
+    }
+
+    fn entry(&self, index: usize) -> Option<Self> {
+        self.value.get(index).map(|value| Self {
+            value,
+            path: format!("{}[{index}]", self.path),
+            ..self.clone()
+        })
+    }
+
+    fn substitute(&self) -> Value {
+        self.log.borrow_mut().push(Substitution {
+            path: self.path.clone(),
+            from: self.from.clone(),
+        });
+        self.value.clone()
+    }
+}
+
+/// Seed that deserializes `S` while filling gaps from `fallback`
+pub struct FilledSeed<'a, S> {
+    pub seed: S,
+    pub fallback: Fallback<'a>,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for FilledSeed<'_, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        self.seed.deserialize(Filled {
+            inner: deserializer,
+            fallback: self.fallback,
+        })
+    }
+}
+
+struct Filled<'a, D> {
+    inner: D,
+    fallback: Fallback<'a>,
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error> {
+                self.inner.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+macro_rules! wrap_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*) => $fields:expr),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error> {
+                let visitor = FilledVisitor {
+                    inner: visitor,
+                    fallback: self.fallback,
+                    fields: $fields,
+                };
+                self.inner.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Filled<'_, D> {
+    type Error = D::Error;
+
+    wrap_deserialize! {
+        deserialize_any() => &[],
+        deserialize_option() => &[],
+        deserialize_seq() => &[],
+        deserialize_newtype_struct(name: &'static str) => &[],
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]) => fields,
+    }
+
+    forward_deserialize! {
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    }
+}
+
+struct FilledVisitor<'a, V> {
+    inner: V,
+    fallback: Fallback<'a>,
+    fields: &'static [&'static str],
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<E: de::Error>(self, $($arg: $ty),*) -> Result<V::Value, E> {
+                self.inner.$method($($arg),*)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for FilledVisitor<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(v: bool),
+        visit_i8(v: i8),
+        visit_i16(v: i16),
+        visit_i32(v: i32),
+        visit_i64(v: i64),
+        visit_i128(v: i128),
+        visit_u8(v: u8),
+        visit_u16(v: u16),
+        visit_u32(v: u32),
+        visit_u64(v: u64),
+        visit_u128(v: u128),
+        visit_f32(v: f32),
+        visit_f64(v: f64),
+        visit_char(v: char),
+        visit_str(v: &str),
+        visit_borrowed_str(v: &'de str),
+        visit_string(v: String),
+        visit_bytes(v: &[u8]),
+        visit_borrowed_bytes(v: &'de [u8]),
+        visit_byte_buf(v: Vec<u8>),
+        visit_none(),
+        visit_unit(),
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<V::Value, D::Error> {
+        self.inner.visit_some(Filled {
+            inner: deserializer,
+            fallback: self.fallback,
+        })
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        self.inner.visit_newtype_struct(Filled {
+            inner: deserializer,
+            fallback: self.fallback,
+        })
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_seq(FilledSeq {
+            inner: Some(seq),
+            fallback: self.fallback,
+            index: 0,
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_map(FilledMap {
+            inner: Some(map),
+            fallback: self.fallback,
+            fields: self.fields,
+            seen: Vec::new(),
+            current: None,
+            missing: Vec::new(),
+            pending: None,
+        })
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_enum(data)
+    }
+}
+
+/// List entries past the end of the translation are taken from the fallback
+struct FilledSeq<'a, A> {
+    inner: Option<A>,
+    fallback: Fallback<'a>,
+    index: usize,
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for FilledSeq<'_, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, A::Error> {
+        let fallback = self.fallback.entry(self.index);
+        self.index += 1;
+
+        let mut seed = Some(seed);
+        if let Some(inner) = self.inner.as_mut() {
+            let element = inner.next_element_seed(LentSeed {
+                seed: &mut seed,
+                fallback: fallback.clone(),
+            })?;
+            if element.is_some() {
+                return Ok(element);
+            }
+            self.inner = None;
+        }
+
+        match (seed, fallback) {
+            (Some(seed), Some(fallback)) => seed_from_value(seed, fallback.substitute()).map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Seed that is only consumed when the underlying sequence has another element
+struct LentSeed<'s, 'a, T> {
+    seed: &'s mut Option<T>,
+    fallback: Option<Fallback<'a>>,
+}
+
+impl<'de, T: DeserializeSeed<'de>> DeserializeSeed<'de> for LentSeed<'_, '_, T> {
+    type Value = T::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T::Value, D::Error> {
+        let seed = self
+            .seed
+            .take()
+            .ok_or_else(|| de::Error::custom("sequence element deserialized twice"))?;
+        match self.fallback {
+            Some(fallback) => FilledSeed { seed, fallback }.deserialize(deserializer),
+            None => seed.deserialize(deserializer),
+        }
+    }
+}
+
+/// Struct fields absent from the translation are taken from the fallback
+struct FilledMap<'a, A> {
+    inner: Option<A>,
+    fallback: Fallback<'a>,
+    fields: &'static [&'static str],
+    seen: Vec<String>,
+    current: Option<String>,
+    missing: Vec<(&'static str, Fallback<'a>)>,
+    pending: Option<Value>,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for FilledMap<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        if let Some(inner) = self.inner.as_mut() {
+            match inner.next_key::<String>()? {
+                Some(key) => {
+                    self.seen.push(key.clone());
+                    self.current = Some(key.clone());
+                    return seed.deserialize(key.into_deserializer()).map(Some);
+                }
+                None => {
+                    self.inner = None;
+                    self.missing = self
+                        .fields
+                        .iter()
+                        .rev()
+                        .filter(|field| !self.seen.iter().any(|seen| seen == *field))
+                        .filter_map(|field| {
+                            self.fallback
+                                .field(field)
+                                .map(|fallback| (*field, fallback))
+                        })
+                        .collect();
+                }
+            }
+        }
+
+        match self.missing.pop() {
+            Some((field, fallback)) => {
+                self.pending = Some(fallback.substitute());
+                seed.deserialize(field.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<T::Value, A::Error> {
+        if let Some(value) = self.pending.take() {
+            return seed_from_value(seed, value);
+        }
+
+        let fallback = self
+            .current
+            .take()
+            .and_then(|key| self.fallback.field(&key));
+        let inner = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| de::Error::custom("value requested before key"))?;
+        match fallback {
+            Some(fallback) => inner.next_value_seed(FilledSeed { seed, fallback }),
+            None => inner.next_value_seed(seed),
+        }
+    }
+}
+
+fn seed_from_value<'de, T: DeserializeSeed<'de>, E: de::Error>(
+    seed: T,
+    value: Value,
+) -> Result<T::Value, E> {
+    seed.deserialize(value).map_err(E::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::model::DateLabels;
+    use std::marker::PhantomData;
+
+    #[test]
+    fn fills_missing_fields_but_keeps_word_lists_whole() {
+        let ru: Value = serde_yaml::from_str(
+            "present: н.в.\n\
+             month_names: [янв, фев, мар, апр, май, июн, июл, авг, сен, окт, ноя, дек]\n\
+             years: [год, года, лет]\n\
+             months: [месяц, месяца, месяцев]\n",
+        )
+        .unwrap();
+        let log = RefCell::new(Vec::new());
+        let seed = FilledSeed {
+            seed: PhantomData::<DateLabels>,
+            fallback: Fallback::new(&ru, "en.dates".to_owned(), "ru".parse().unwrap(), &log),
+        };
+
+        let en = seed
+            .deserialize(serde_yaml::Deserializer::from_str(
+                "present: present\nyears: [yr, yrs]\n",
+            ))
+            .unwrap();
+
+        assert_eq!(en.years, ["yr", "yrs"]);
+        assert_eq!(en.months, ["месяц", "месяца", "месяцев"]);
+        let paths: Vec<String> = log.into_inner().into_iter().map(|s| s.path).collect();
+        assert_eq!(paths, ["en.dates.month_names", "en.dates.months"]);
+    }
+}
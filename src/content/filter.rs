@@ -0,0 +1,129 @@
+use super::model::{Project, Skill};
+
+/// Separator of technologies in the `tech` query parameter, so it may not
+/// appear in a technology name
+pub const SEPARATOR: char = ',';
+
+/// Technologies selected to narrow the portfolio, shared in the URL as
+/// `?tech=Ansible,Puppet`. Names compare case-insensitively, so `Rust`
+/// selects the `RUST` pill.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct TechFilter(Vec<String>);
+
+impl TechFilter {
+    pub fn new<S: AsRef<str>>(technologies: &[S]) -> Self {
+        Self(
+            technologies
+                .iter()
+                .map(|technology| technology.as_ref().trim().to_owned())
+                .filter(|technology| !technology.is_empty())
+                .collect(),
+        )
+    }
+
+    /// Read the value of the `tech` query parameter
+    pub fn from_query(value: &str) -> Self {
+        Self::new(&value.split(SEPARATOR).collect::<Vec<_>>())
+    }
+
+    /// Value for the `tech` query parameter
+    pub fn to_query(&self) -> String {
+        self.0.join(&SEPARATOR.to_string())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn technologies(&self) -> &[String] {
+        &self.0
+    }
+
+    pub fn contains(&self, technology: &str) -> bool {
+        self.0
+            .iter()
+            .any(|selected| selected.eq_ignore_ascii_case(technology))
+    }
+
+    /// Whether any of `technologies` is selected, always true without a selection
+    pub fn matches(&self, technologies: &[String]) -> bool {
+        self.is_empty()
+            || technologies
+                .iter()
+                .any(|technology| self.contains(technology))
+    }
+
+    /// Projects to show for this selection
+    pub fn projects<'a>(&self, projects: &'a [Project]) -> Vec<&'a Project> {
+        projects
+            .iter()
+            .filter(|project| self.matches(&project.technologies))
+            .collect()
+    }
+
+    /// Whether a skill covers a selected technology, to highlight it
+    pub fn highlights(&self, skill: &Skill) -> bool {
+        !self.is_empty() && self.matches(&skill.technologies)
+    }
+
+    /// Selection after clicking something that selects `technologies`:
+    /// clicking the current selection again clears it
+    pub fn toggle<S: AsRef<str>>(&self, technologies: &[S]) -> Self {
+        let next = Self::new(technologies);
+        let same = next.0.len() == self.0.len()
+            && next.0.iter().all(|technology| self.contains(technology));
+        if same { Self::default() } else { next }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{Lang, fixture};
+
+    #[test]
+    fn round_trips_through_the_query() {
+        let filter = TechFilter::from_query("Ansible, Puppet,,");
+        assert_eq!(filter.technologies(), ["Ansible", "Puppet"]);
+        assert_eq!(filter.to_query(), "Ansible,Puppet");
+        assert!(TechFilter::from_query("").is_empty());
+    }
+
+    #[test]
+    fn narrows_projects_and_highlights_skills() {
+        let content = fixture();
+        let en: Lang = "en".parse().unwrap();
+        let portfolio = &content.get_or_default(&en).portfolio;
+
+        let all = TechFilter::default();
+        assert_eq!(
+            all.projects(&portfolio.projects).len(),
+            portfolio.projects.len()
+        );
+        assert!(portfolio.skills.iter().all(|skill| !all.highlights(skill)));
+
+        let puppet = TechFilter::from_query("puppet");
+        let projects = puppet.projects(&portfolio.projects);
+        assert!(!projects.is_empty() && projects.len() < portfolio.projects.len());
+        assert!(
+            projects
+                .iter()
+                .all(|project| project.technologies.iter().any(|t| t == "Puppet"))
+        );
+        let highlighted: Vec<&str> = portfolio
+            .skills
+            .iter()
+            .filter(|skill| puppet.highlights(skill))
+            .map(|skill| skill.name.as_str())
+            .collect();
+        assert_eq!(highlighted, ["Ansible / Puppet / Configuration Management"]);
+    }
+
+    #[test]
+    fn clicking_the_selection_again_clears_it() {
+        let filter = TechFilter::default().toggle(&["Terraform"]);
+        assert_eq!(filter.technologies(), ["Terraform"]);
+        assert!(filter.toggle(&["terraform"]).is_empty());
+        assert_eq!(filter.toggle(&["Docker"]).technologies(), ["Docker"]);
+    }
+}
@@ -0,0 +1,45 @@
+use serde::Deserialize;
+
+/// Icons bundled with the site as inline SVG, named in content in kebab-case,
+/// e.g. `telegram` or `external-link`
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum Icon {
+    CheckCircle,
+    ChevronLeft,
+    ChevronRight,
+    Close,
+    Copy,
+    Download,
+    Email,
+    ExternalLink,
+    Github,
+    Globe,
+    Linkedin,
+    Phone,
+    Printer,
+    Telegram,
+    Terminal,
+    Vk,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_names_and_rejects_the_rest() {
+        let icons: Vec<Icon> = serde_yaml::from_str("[telegram, vk, external-link]").unwrap();
+        assert_eq!(icons, [Icon::Telegram, Icon::Vk, Icon::ExternalLink]);
+
+        let error = serde_yaml::from_str::<Icon>("fab fa-telegram")
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(
+            error.starts_with("unknown variant `fab fa-telegram`, expected one of"),
+            "{error}"
+        );
+    }
+}
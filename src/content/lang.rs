@@ -0,0 +1,112 @@
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use std::fmt;
+use std::str::FromStr;
+
+/// Language code used as a top-level key in the content file and as the
+/// `/:lang` route segment, e.g. `ru`, `en` or `pt-br`
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Lang(String);
+
+impl Lang {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// English, the language of the placeholder content
+impl Default for Lang {
+    fn default() -> Self {
+        Self("en".to_owned())
+    }
+}
+
+impl FromStr for Lang {
+    type Err = String;
+
+    /// Accept a lowercase primary subtag of 2-3 letters with optional
+    /// alphanumeric subtags separated by `-`
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        let mut parts = code.split('-');
+        let primary = parts.next().unwrap_or_default();
+        let primary_ok =
+            (2..=3).contains(&primary.len()) && primary.bytes().all(|b| b.is_ascii_lowercase());
+        let rest_ok = parts.all(|part| {
+            (2..=8).contains(&part.len())
+                && part
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        });
+
+        if primary_ok && rest_ok {
+            Ok(Self(code.to_owned()))
+        } else {
+            Err(format!("invalid language code `{code}`"))
+        }
+    }
+}
+
+impl fmt::Display for Lang {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Lang {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LangVisitor;
+
+        impl Visitor<'_> for LangVisitor {
+            type Value = Lang;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a language code such as `ru` or `en`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Lang, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(LangVisitor)
+    }
+}
+
+#[cfg(test)]
+impl schemars::JsonSchema for Lang {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Lang".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Language code such as `ru`, `en` or `pt-br`",
+            "type": "string",
+            "pattern": "^[a-z]{2,3}(-[a-z0-9]{2,8})*$",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_language_codes() {
+        for code in ["ru", "en", "pt-br", "zh-hant-tw", "sr-latn"] {
+            assert_eq!(code.parse::<Lang>().unwrap().as_str(), code);
+        }
+        for code in ["", "e", "english", "ru-", "ru_ru", "-en", "en-x", "ру"] {
+            assert!(code.parse::<Lang>().is_err(), "{code}");
+        }
+    }
+
+    #[test]
+    fn accepts_lowercase_codes_only() {
+        for code in ["EN", "Ru", "pt-BR"] {
+            assert_eq!(
+                code.parse::<Lang>().unwrap_err(),
+                format!("invalid language code `{code}`")
+            );
+        }
+    }
+}
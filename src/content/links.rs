@@ -0,0 +1,318 @@
+use std::fmt;
+use std::path::{Component, Path};
+use url::{Host, Url};
+
+use super::lang::Lang;
+use super::model::{Content, LangContent};
+use super::rich_text::{Inline, RichText};
+use super::sanitize::{LinkTarget, SAFE_SCHEMES, link_target};
+
+// Offline checks for everything the content links to: local assets must exist
+// under `static/`, URLs must parse and use an allowed scheme, and email
+// addresses and phone numbers must be usable in `mailto:` and `tel:` links.
+
+/// Prefix of local asset paths, served from the `static/` directory
+const STATIC_PREFIX: &str = "/static/";
+
+/// A link or asset reference in the content that does not resolve
+#[derive(Clone, PartialEq, Debug)]
+pub struct BrokenLink {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for BrokenLink {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl Content {
+    /// Check every asset path, URL, email and phone number, looking up local
+    /// assets relative to `site_root`, the directory that holds `static/`
+    pub fn link_report(&self, site_root: &Path) -> Vec<BrokenLink> {
+        self.languages()
+            .flat_map(|(lang, content)| check_links(lang, content, site_root))
+            .collect()
+    }
+}
+
+/// Check the links of one language
+pub fn check_links(lang: &Lang, content: &LangContent, site_root: &Path) -> Vec<BrokenLink> {
+    let mut report = Report {
+        site_root,
+        broken: Vec::new(),
+    };
+
+    if let Some(logo) = &content.hero.logo {
+        report.asset(format!("{lang}.hero.logo"), logo);
+    }
+    report.asset(format!("{lang}.about.photo"), &content.about.photo);
+    for (i, exp) in content.about.work_experience.iter().enumerate() {
+        let path = format!("{lang}.about.work_experience[{i}].description");
+        report.texts(&path, &exp.description);
+    }
+
+    for (i, project) in content.portfolio.projects.iter().enumerate() {
+        let path = format!("{lang}.portfolio.projects[{i}]");
+        if let Some(link) = &project.link {
+            report.url(format!("{path}.link"), link);
+        }
+        report.texts(&format!("{path}.description"), &project.description);
+        if let Some(cover) = &project.cover {
+            report.asset(format!("{path}.cover.src"), &cover.src);
+        }
+        for (j, screenshot) in project.screenshots.iter().enumerate() {
+            report.asset(format!("{path}.screenshots[{j}].src"), &screenshot.src);
+        }
+    }
+
+    let contact = &content.contact;
+    // Same separators the contact section strips when building the `tel:` link
+    if let Some(phone) = &contact.phone
+        && !is_phone(&phone.replace([' ', '(', ')', '-'], ""))
+    {
+        let message = format!("invalid phone number `{phone}`");
+        report.push(format!("{lang}.contact.phone"), message);
+    }
+    if !is_email(&contact.email) {
+        let message = format!("invalid email address `{}`", contact.email);
+        report.push(format!("{lang}.contact.email"), message);
+    }
+    for (i, network) in contact.social_networks.iter().enumerate() {
+        report.url(
+            format!("{lang}.contact.social_networks[{i}].url"),
+            &network.url,
+        );
+    }
+
+    report.broken
+}
+
+struct Report<'a> {
+    site_root: &'a Path,
+    broken: Vec<BrokenLink>,
+}
+
+impl Report<'_> {
+    fn push(&mut self, path: String, message: String) {
+        self.broken.push(BrokenLink { path, message });
+    }
+
+    fn asset(&mut self, path: String, value: &str) {
+        let Some(relative) = value.strip_prefix('/') else {
+            self.push(
+                path,
+                format!("`{value}` must be an absolute path such as `/static/photo.jpg`"),
+            );
+            return;
+        };
+        if !value.starts_with(STATIC_PREFIX) {
+            self.push(path, format!("`{value}` is outside {STATIC_PREFIX}"));
+            return;
+        }
+        let relative = Path::new(relative.split(['?', '#']).next().unwrap_or_default());
+        let escapes = relative
+            .components()
+            .any(|component| !matches!(component, Component::Normal(_)));
+        if escapes || !self.site_root.join(relative).is_file() {
+            self.push(path, format!("`{value}` does not exist"));
+        }
+    }
+
+    fn url(&mut self, path: String, value: &str) {
+        let checked = match link_target(value) {
+            Ok(LinkTarget::Site) if value.starts_with('#') => Ok(()),
+            Ok(LinkTarget::Site) => return self.asset(path, value),
+            // Protocol-relative, checked as the `https:` URL the page resolves it to
+            Ok(LinkTarget::External(_)) if value.starts_with("//") => {
+                check_url(&format!("https:{value}"))
+            }
+            Ok(LinkTarget::External(_)) => check_url(value),
+            Err(message) => Err(message),
+        };
+        if let Err(message) = checked {
+            self.push(path, message);
+        }
+    }
+
+    fn texts(&mut self, path: &str, texts: &[RichText]) {
+        for (index, text) in texts.iter().enumerate() {
+            self.inlines(&format!("{path}[{index}]"), &text.0);
+        }
+    }
+
+    fn inlines(&mut self, path: &str, inlines: &[Inline]) {
+        for inline in inlines {
+            match inline {
+                Inline::Link { url, .. } | Inline::Chip { url, .. } => {
+                    self.url(path.to_owned(), url)
+                }
+                Inline::Strong(children) | Inline::Emphasis(children) => {
+                    self.inlines(path, children)
+                }
+                Inline::Html(sanitized) => {
+                    for href in hrefs(&sanitized.html) {
+                        self.url(path.to_owned(), &href);
+                    }
+                }
+                Inline::Text(_) | Inline::Code(_) => {}
+            }
+        }
+    }
+}
+
+/// Check that `value` is a well-formed URL with an allowed scheme and a valid target
+fn check_url(value: &str) -> Result<(), String> {
+    let url = Url::parse(value).map_err(|err| format!("malformed URL `{value}`: {err}"))?;
+    let scheme = url.scheme();
+    if !SAFE_SCHEMES.contains(&scheme) {
+        return Err(format!(
+            "`{value}` uses `{scheme}:`, allowed schemes are {}",
+            SAFE_SCHEMES.join(", ")
+        ));
+    }
+
+    match scheme {
+        "https" => match url.host() {
+            Some(Host::Domain(domain)) if is_domain(domain) => Ok(()),
+            _ => Err(format!("`{value}` must point to a domain name")),
+        },
+        "mailto" => {
+            let addresses = url.path();
+            let valid = !addresses.is_empty() && addresses.split(',').all(is_email);
+            valid
+                .then_some(())
+                .ok_or_else(|| format!("`{value}` has an invalid email address"))
+        }
+        "tel" => {
+            let number = url.path().replace(['-', '.', '(', ')'], "");
+            is_phone(&number)
+                .then_some(())
+                .ok_or_else(|| format!("`{value}` has an invalid phone number"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// An international phone number: `+` followed by 7 to 15 digits
+fn is_phone(number: &str) -> bool {
+    number.strip_prefix('+').is_some_and(|digits| {
+        (7..=15).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+fn is_email(address: &str) -> bool {
+    let Some((local, domain)) = address.rsplit_once('@') else {
+        return false;
+    };
+    let local_valid = (1..=64).contains(&local.len())
+        && !local.starts_with('.')
+        && !local.ends_with('.')
+        && !local.contains("..")
+        && local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c));
+    local_valid && is_domain(domain)
+}
+
+/// A domain of at least two labels ending in an alphabetic top-level domain
+fn is_domain(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+    let valid_label = |label: &&str| {
+        (1..=63).contains(&label.len())
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    let tld_valid = labels.last().is_some_and(|tld| {
+        tld.len() >= 2 && (tld.chars().all(|c| c.is_ascii_alphabetic()) || tld.starts_with("xn--"))
+    });
+    labels.len() >= 2 && labels.iter().all(valid_label) && tld_valid
+}
+
+/// Values of the `href` attributes the sanitizer kept, with entities decoded
+fn hrefs(html: &str) -> Vec<String> {
+    html.split(" href=\"")
+        .skip(1)
+        .filter_map(|rest| rest.split_once('"'))
+        .map(|(href, _)| {
+            href.replace("&quot;", "\"")
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&amp;", "&")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::fixture;
+    use crate::content::model::Image;
+
+    fn site_root() -> &'static Path {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+    }
+
+    #[test]
+    fn embedded_links_resolve() {
+        let report = fixture().link_report(site_root());
+        let lines: Vec<String> = report.iter().map(ToString::to_string).collect();
+        assert!(report.is_empty(), "broken links:\n{}", lines.join("\n"));
+    }
+
+    #[test]
+    fn reports_missing_assets_and_bad_urls_by_path() {
+        let content = fixture();
+        let ru: Lang = "ru".parse().unwrap();
+        let mut broken = content.get_or_default(&ru).clone();
+        broken.hero.logo = Some("/static/logo.png".to_owned());
+        broken.about.photo = "/static/../Cargo.toml".to_owned();
+        broken.contact.email = "i@itmage".to_owned();
+        broken.contact.phone = Some("8 (987) 777".to_owned());
+        broken.contact.social_networks[0].url = "http://t.me/itmagelab".to_owned();
+        broken.contact.social_networks[1].url = "mailto:i@@itmage.ru".to_owned();
+        broken.portfolio.projects[0].link = Some("tel:+7-987".to_owned());
+        broken.portfolio.projects[0].screenshots = vec![Image {
+            src: "/static/baza.png".to_owned(),
+            alt: "Baza".to_owned(),
+        }];
+        broken.portfolio.projects[1].description = vec![
+            "see [docs](https://example)".parse().unwrap(),
+            "<a href=\"//localhost/docs\">docs</a>".parse().unwrap(),
+        ];
+
+        let paths: Vec<String> = check_links(&ru, &broken, site_root())
+            .into_iter()
+            .map(|broken| broken.path)
+            .collect();
+
+        assert_eq!(
+            paths,
+            [
+                "ru.hero.logo",
+                "ru.about.photo",
+                "ru.portfolio.projects[0].link",
+                "ru.portfolio.projects[0].screenshots[0].src",
+                "ru.portfolio.projects[1].description[0]",
+                "ru.portfolio.projects[1].description[1]",
+                "ru.contact.phone",
+                "ru.contact.email",
+                "ru.contact.social_networks[0].url",
+                "ru.contact.social_networks[1].url",
+            ]
+        );
+    }
+
+    #[test]
+    fn accepts_allowed_schemes() {
+        assert!(check_url("https://kubcoin.ru").is_ok());
+        assert!(check_url("mailto:i@itmage.ru?subject=Hi").is_ok());
+        assert!(check_url("tel:+79877773445").is_ok());
+        assert!(check_url("tg://resolve?domain=itmagelab").is_ok());
+        assert!(check_url("https://localhost").is_err());
+        assert!(check_url("http://itmage.ru").is_err());
+        assert!(check_url("ftp://itmage.ru").is_err());
+    }
+}
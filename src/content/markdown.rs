@@ -0,0 +1,203 @@
+use super::date::Month;
+use super::model::LangContent;
+use super::rich_text::{Inline, RichText};
+
+// Résumé of one language as Markdown, to paste into READMEs, job boards and
+// chats. `content dist` publishes it as `cv.<lang>.md` and the page copies the
+// same text to the clipboard. Markup in descriptions, including pasted HTML,
+// becomes Markdown emphasis and links.
+
+/// File name of the export for a language, e.g. `cv.en.md`
+pub fn file_name(lang: &str) -> String {
+    format!("cv.{lang}.md")
+}
+
+/// Render the résumé, with durations of current positions counted up to `today`
+pub fn cv(content: &LangContent, today: Month) -> String {
+    let (hero, about, portfolio, contact) = (
+        &content.hero,
+        &content.about,
+        &content.portfolio,
+        &content.contact,
+    );
+    let dates = &content.language.dates;
+    let mut blocks = vec![format!("# {}", escape(&hero.subtitle)), escape(&hero.title)];
+
+    blocks.push(format!("## {}", escape(&about.title)));
+    blocks.push(escape(about.description.trim()));
+    blocks.push(list(about.skills.iter().map(|item| escape(item))));
+
+    blocks.push(format!("## {}", escape(&about.work_experience_title)));
+    for exp in &about.work_experience {
+        blocks.push(format!(
+            "### {} — {}",
+            escape(&exp.position),
+            escape(&exp.company)
+        ));
+        blocks.push(format!(
+            "*{} ({})*",
+            dates.range(exp.start, exp.end),
+            dates.duration(exp.duration(today))
+        ));
+        blocks.push(list(exp.description.iter().map(RichText::markdown)));
+    }
+
+    blocks.push(format!("## {}", escape(&portfolio.projects_title)));
+    for project in &portfolio.projects {
+        blocks.push(match &project.link {
+            Some(url) => format!("### [{}]({url})", escape(&project.name)),
+            None => format!("### {}", escape(&project.name)),
+        });
+        let period = project
+            .period
+            .as_ref()
+            .map(|period| dates.range(period.start, period.end));
+        let meta: Vec<String> = project
+            .role
+            .iter()
+            .map(|role| escape(role))
+            .chain(period)
+            .collect();
+        if !meta.is_empty() {
+            blocks.push(format!("*{}*", meta.join(" · ")));
+        }
+        blocks.extend(project.description.iter().map(RichText::markdown));
+        let technologies: Vec<String> = project
+            .technologies
+            .iter()
+            .map(|technology| format!("`{technology}`"))
+            .collect();
+        blocks.push(technologies.join(" "));
+    }
+
+    blocks.push(format!("## {}", escape(&portfolio.skills_title)));
+    blocks.push(list(portfolio.skills_by_category().into_iter().map(
+        |(category, skills)| {
+            let skills: Vec<String> = skills
+                .iter()
+                .map(|skill| escape(&portfolio.skill_label(skill)))
+                .collect();
+            format!("**{}:** {}", escape(&category.name), skills.join(", "))
+        },
+    )));
+
+    blocks.push(format!("## {}", escape(&contact.title)));
+    let phone = contact.phone.iter().map(|phone| {
+        let number = phone.replace(&[' ', '(', ')', '-'][..], "");
+        format!("{}: [{phone}](tel:{number})", escape(&contact.phone_label))
+    });
+    let email = format!(
+        "{}: [{}](mailto:{})",
+        escape(&contact.email_label),
+        escape(&contact.email),
+        contact.email
+    );
+    let networks = contact
+        .social_networks
+        .iter()
+        .map(|network| format!("[{}]({})", escape(&network.name), network.url));
+    blocks.push(list(phone.chain(Some(email)).chain(networks)));
+
+    let blocks: Vec<String> = blocks
+        .into_iter()
+        .filter(|block| !block.is_empty())
+        .collect();
+    blocks.join("\n\n") + "\n"
+}
+
+impl RichText {
+    /// The text as Markdown, pasted HTML converted to the same markup
+    pub fn markdown(&self) -> String {
+        let mut out = String::new();
+        push_markdown(&mut out, &self.0);
+        out
+    }
+}
+
+fn push_markdown(out: &mut String, nodes: &[Inline]) {
+    for node in nodes {
+        match node {
+            Inline::Text(text) => out.push_str(&escape(text)),
+            Inline::Strong(inner) => {
+                out.push_str("**");
+                push_markdown(out, inner);
+                out.push_str("**");
+            }
+            Inline::Emphasis(inner) => {
+                out.push('*');
+                push_markdown(out, inner);
+                out.push('*');
+            }
+            Inline::Code(code) => out.push_str(&format!("`{code}`")),
+            Inline::Link { text, url } | Inline::Chip { text, url } => {
+                out.push_str(&format!("[{}]({url})", escape(text)));
+            }
+            Inline::Html(sanitized) => out.push_str(&sanitized.markdown()),
+        }
+    }
+}
+
+fn list(items: impl Iterator<Item = String>) -> String {
+    items
+        .map(|item| format!("- {item}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Escape the characters Markdown would read as markup
+pub(super) fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::fixture;
+
+    #[test]
+    fn converts_markup_and_html_to_markdown() {
+        let text: RichText = "[[Baza]](https://github.com/itmagelab/baza) is **fast**, 2\\*3"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            text.markdown(),
+            "[Baza](https://github.com/itmagelab/baza) is **fast**, 2\\*3"
+        );
+        let html: RichText = r#"<a href="https://kubcoin.ru"><i class="x"></i>Kubcoin</a> &mdash; <strong>ok</strong> <a>plain</a>"#
+            .parse()
+            .unwrap();
+        assert_eq!(
+            html.markdown(),
+            "[Kubcoin](https://kubcoin.ru) — **ok** plain"
+        );
+    }
+
+    #[test]
+    fn renders_every_section() {
+        let content = fixture();
+        let en = content.get(&"en".parse().unwrap()).unwrap();
+        let cv = cv(en, Month::new(2025, 6).unwrap());
+
+        assert!(cv.starts_with("# Andrey Semenov\n\n"));
+        for heading in [
+            &en.about.title,
+            &en.about.work_experience_title,
+            &en.portfolio.projects_title,
+            &en.portfolio.skills_title,
+            &en.contact.title,
+        ] {
+            assert!(cv.contains(&format!("\n## {heading}\n")), "{heading}");
+        }
+        assert!(cv.contains("[Baza](https://github.com/itmagelab/baza)"));
+        assert!(cv.contains("`Terraform` `Docker`"));
+        assert!(cv.contains("- [Telegram](https://t.me/itmagelab)\n"));
+        assert!(!cv.contains("\n\n\n"));
+    }
+}
@@ -0,0 +1,547 @@
+use serde::Deserialize;
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, Visitor};
+use serde_yaml::Value;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::marker::PhantomData;
+
+use super::date::Month;
+use super::fallback::{Fallback, FilledSeed, Substitution};
+use super::icon::Icon;
+use super::lang::Lang;
+use super::rich_text::RichText;
+use super::skills::{Proficiency, check_skills};
+use super::validate::{month_names, non_empty, plural_forms};
+use super::variables;
+
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct HeroContent {
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub title: String,
+    pub subtitle: String,
+    /// Path of an SVG, PNG or JPEG logo under `/static/`, the terminal icon is
+    /// shown without one. The favicons are generated from the default language's.
+    pub logo: Option<String>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct AboutContent {
+    pub photo: String,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub title: String,
+    pub description: String,
+    pub skills: Vec<String>,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub work_experience_title: String,
+    pub work_experience: Vec<WorkExperience>,
+    pub cv: CvLabels,
+}
+
+/// Labels of the CV downloads offered under the work experience
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct CvLabels {
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub title: String,
+    /// Link to the JSON Resume export
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub json_resume: String,
+    /// Link to the PDF résumé
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub pdf: String,
+    /// Button copying the résumé as Markdown
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub markdown: String,
+    /// Shown on the button once the Markdown is copied
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub copied: String,
+    /// Button opening the printable CV with the print dialog
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub print: String,
+    /// Link from the printable CV back to the main page
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub back: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct WorkExperience {
+    pub start: Month,
+    /// `None` while the position is current
+    pub end: Option<Month>,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub position: String,
+    pub company: String,
+    pub description: Vec<RichText>,
+    /// Shown with a draft marker in debug builds and left out of release builds
+    #[serde(default)]
+    pub draft: bool,
+    /// `false` hides the entry the same way as `draft: true`
+    #[serde(default = "visible")]
+    pub visible: bool,
+}
+
+/// Localized words used to format months, ranges and durations
+#[derive(Deserialize, Clone, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct DateLabels {
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub present: String,
+    #[serde(deserialize_with = "month_names")]
+    #[cfg_attr(test, schemars(length(min = 12, max = 12)))]
+    pub month_names: Vec<String>,
+    #[serde(deserialize_with = "plural_forms")]
+    #[cfg_attr(test, schemars(length(min = 1, max = 3)))]
+    pub years: Vec<String>,
+    #[serde(deserialize_with = "plural_forms")]
+    #[cfg_attr(test, schemars(length(min = 1, max = 3)))]
+    pub months: Vec<String>,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct PortfolioContent {
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub title: String,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub projects_title: String,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub skills_title: String,
+    pub projects: Vec<Project>,
+    pub gallery: GalleryLabels,
+    pub filter: FilterLabels,
+    /// Groups skills are shown in, in display order
+    pub skill_categories: Vec<SkillCategory>,
+    pub skill_levels: SkillLevels,
+    /// Shown before the year a skill was last used, e.g. `until 2019`
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub last_used_label: String,
+    pub skills: Vec<Skill>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct Project {
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub name: String,
+    pub description: Vec<RichText>,
+    pub link: Option<String>,
+    pub technologies: Vec<String>,
+    /// When the project ran, not shown without one
+    pub period: Option<Period>,
+    /// Part played in the project, e.g. `Author` or `Lead engineer`
+    pub role: Option<String>,
+    /// Image shown at the top of the card
+    pub cover: Option<Image>,
+    /// Screenshots shown as thumbnails that open in a lightbox
+    #[serde(default)]
+    pub screenshots: Vec<Image>,
+    /// Shown with a draft marker in debug builds and left out of release builds
+    #[serde(default)]
+    pub draft: bool,
+    /// `false` hides the entry the same way as `draft: true`
+    #[serde(default = "visible")]
+    pub visible: bool,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct Period {
+    pub start: Month,
+    /// `None` while the project is ongoing
+    pub end: Option<Month>,
+}
+
+/// Image under `/static/` with the text read out in its place
+#[derive(Deserialize, Clone, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct Image {
+    pub src: String,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub alt: String,
+}
+
+/// Labels of the technology filter above the project list
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct FilterLabels {
+    /// Shown before the selected technologies, e.g. `Projects using`
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub active: String,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub clear: String,
+    /// Shown when no project uses the selected technologies
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub empty: String,
+}
+
+/// Labels of the screenshot lightbox controls, read by screen readers
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct GalleryLabels {
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub open: String,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub close: String,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub previous: String,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub next: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct Skill {
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub name: String,
+    /// Id of one of the `skill_categories`
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub category: String,
+    /// Left out until the author rates the skill
+    pub level: Option<Proficiency>,
+    /// Year the skill was first used, years of use are not shown without it
+    pub since: Option<u16>,
+    /// Year the skill was last used, `None` while it is still in use
+    pub last_used: Option<u16>,
+    /// Project technologies named in the skill, selected when the skill is clicked
+    #[serde(default)]
+    pub technologies: Vec<String>,
+    /// Shown with a draft marker in debug builds and left out of release builds
+    #[serde(default)]
+    pub draft: bool,
+    /// `false` hides the entry the same way as `draft: true`
+    #[serde(default = "visible")]
+    pub visible: bool,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct SkillCategory {
+    /// Language-neutral id skills refer to
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub id: String,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub name: String,
+}
+
+/// Localized proficiency levels, shown next to each skill and in the legend
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct SkillLevels {
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub title: String,
+    pub beginner: SkillLevel,
+    pub intermediate: SkillLevel,
+    pub advanced: SkillLevel,
+    pub expert: SkillLevel,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct SkillLevel {
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct ContactContent {
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub title: String,
+    /// Left out of the page when empty, e.g. removed by an overlay
+    pub phone: Option<String>,
+    pub phone_label: String,
+    pub email: String,
+    pub email_label: String,
+    /// Link to the vCard with the phone, email and profiles
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub save_contact: String,
+    /// Caption of the QR code of the vCard
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub scan_contact: String,
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub social_title: String,
+    pub social_networks: Vec<SocialNetwork>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct SocialNetwork {
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub name: String,
+    pub url: String,
+    pub icon: Icon,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct FooterContent {
+    pub copyright: String,
+    pub credits: String,
+}
+
+/// Settings of a language itself rather than of a page section
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct LanguageInfo {
+    /// Native name of the language shown in the language switcher
+    #[serde(deserialize_with = "non_empty")]
+    #[cfg_attr(test, schemars(length(min = 1)))]
+    pub name: String,
+    /// Language that supplies any field or list entry missing from this one
+    pub fallback: Option<Lang>,
+    /// Named constants text can reference as `{name}`, next to the built-in
+    /// `{years_experience}` and `{current_year}`
+    #[serde(default)]
+    pub variables: BTreeMap<String, String>,
+    pub dates: DateLabels,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct LangContent {
+    pub language: LanguageInfo,
+    pub hero: HeroContent,
+    pub about: AboutContent,
+    pub portfolio: PortfolioContent,
+    pub contact: ContactContent,
+    pub footer: FooterContent,
+}
+
+/// All languages from the content file, in the order they are declared
+#[derive(Clone, PartialEq)]
+pub struct Content {
+    languages: Vec<(Lang, LangContent)>,
+}
+
+/// A single empty language, shown when the content cannot be parsed at all
+impl Default for Content {
+    fn default() -> Self {
+        Self {
+            languages: vec![(Lang::default(), LangContent::default())],
+        }
+    }
+}
+
+impl Content {
+    /// Get content for the specified language
+    pub fn get(&self, lang: &Lang) -> Option<&LangContent> {
+        self.languages
+            .iter()
+            .find(|(code, _)| code == lang)
+            .map(|(_, content)| content)
+    }
+
+    /// Get content for the specified language, or the default language if it is missing
+    pub fn get_or_default(&self, lang: &Lang) -> &LangContent {
+        self.get(lang).unwrap_or(&self.languages[0].1)
+    }
+
+    /// Iterate over all languages in declaration order
+    pub fn languages(&self) -> impl Iterator<Item = (&Lang, &LangContent)> {
+        self.languages.iter().map(|(code, content)| (code, content))
+    }
+
+    /// The first language in the content file, never missing as an empty file is rejected
+    pub fn default_lang(&self) -> &Lang {
+        &self.languages[0].0
+    }
+}
+
+impl Content {
+    /// Parse the content file, filling gaps in each translation from its `fallback` language
+    /// and resolving text variables as of `today`
+    pub fn from_yaml(
+        source: &str,
+        today: Month,
+    ) -> Result<(Content, Vec<Substitution>), serde_yaml::Error> {
+        let mut raw: Value = serde_yaml::from_str(source)?;
+        // Parse the text as written first, so errors point at their line and column,
+        // then again once the variables computed from it are resolved
+        let (content, _) = parse(&raw, serde_yaml::Deserializer::from_str(source))?;
+        variables::resolve(&mut raw, &content, today)
+            .map_err(<serde_yaml::Error as de::Error>::custom)?;
+        let (mut content, log) = parse(&raw, &raw)?;
+
+        for (lang, lang_content) in &mut content.languages {
+            let work = &mut lang_content.about.work_experience;
+            let reversed = work
+                .iter()
+                .position(|exp| exp.end.is_some_and(|end| end < exp.start));
+            if let Some(index) = reversed {
+                return Err(de::Error::custom(format!(
+                    "{lang}.about.work_experience[{index}]: end is before start"
+                )));
+            }
+            // Most recent position first
+            work.sort_by_key(|exp| std::cmp::Reverse(exp.start));
+
+            let projects = &lang_content.portfolio.projects;
+            let reversed = projects.iter().position(|project| {
+                project
+                    .period
+                    .as_ref()
+                    .is_some_and(|period| period.end.is_some_and(|end| end < period.start))
+            });
+            if let Some(index) = reversed {
+                return Err(de::Error::custom(format!(
+                    "{lang}.portfolio.projects[{index}].period: end is before start"
+                )));
+            }
+
+            check_skills(lang, &lang_content.portfolio)
+                .map_err(<serde_yaml::Error as de::Error>::custom)?;
+        }
+
+        Ok((content, log))
+    }
+}
+
+fn visible() -> bool {
+    true
+}
+
+fn parse<'de, D: Deserializer<'de>>(
+    raw: &Value,
+    deserializer: D,
+) -> Result<(Content, Vec<Substitution>), D::Error> {
+    let log = RefCell::new(Vec::new());
+    let seed = ContentSeed { raw, log: &log };
+    let content = seed.deserialize(deserializer)?;
+    Ok((content, log.into_inner()))
+}
+
+struct ContentSeed<'a> {
+    raw: &'a Value,
+    log: &'a RefCell<Vec<Substitution>>,
+}
+
+/// Resolve the fallback language declared for `lang` in the raw content and its raw content
+pub fn fallback_for<'a>(raw: &'a Value, lang: &Lang) -> Result<Option<(Lang, &'a Value)>, String> {
+    let declared = raw
+        .get(lang.as_str())
+        .and_then(declared_fallback)
+        .and_then(Value::as_str);
+    let Some(declared) = declared else {
+        return Ok(None);
+    };
+
+    let from: Lang = declared.parse()?;
+    if from == *lang {
+        return Err(format!("language `{lang}` cannot fall back to itself"));
+    }
+    let Some(value) = raw.get(from.as_str()) else {
+        return Err(format!(
+            "fallback language `{from}` of `{lang}` is not defined"
+        ));
+    };
+    if declared_fallback(value).is_some() {
+        return Err(format!(
+            "fallback language `{from}` of `{lang}` must not declare a fallback itself"
+        ));
+    }
+    Ok(Some((from, value)))
+}
+
+fn declared_fallback(content: &Value) -> Option<&Value> {
+    content.get("language")?.get("fallback")
+}
+
+impl<'de> DeserializeSeed<'de> for ContentSeed<'_> {
+    type Value = Content;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Content, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ContentSeed<'_> {
+    type Value = Content;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of language codes to language content")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Content, A::Error> {
+        let mut languages: Vec<(Lang, LangContent)> = Vec::new();
+        while let Some(lang) = map.next_key::<Lang>()? {
+            if languages.iter().any(|(code, _)| *code == lang) {
+                return Err(de::Error::custom(format!("duplicate language `{lang}`")));
+            }
+            let content = match fallback_for(self.raw, &lang).map_err(de::Error::custom)? {
+                Some((from, value)) => map.next_value_seed(FilledSeed {
+                    seed: PhantomData::<LangContent>,
+                    fallback: Fallback::new(value, lang.to_string(), from, self.log),
+                })?,
+                None => map.next_value()?,
+            };
+            languages.push((lang, content));
+        }
+        if languages.is_empty() {
+            return Err(de::Error::custom(
+                "content must define at least one language",
+            ));
+        }
+        Ok(Content { languages })
+    }
+}
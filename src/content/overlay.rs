@@ -0,0 +1,185 @@
+use serde::Deserialize;
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+
+// An overlay adjusts the merged content for one kind of build, so the same
+// sources give both the full site and, say, a public mirror without the phone
+// number. Overlays live in `content/overlays/<name>.yaml` and address values
+// by path, `*` matching every language, key or list entry:
+//
+//     set:
+//       "*.contact.email": "hello@example.com"
+//     remove: ["*.contact.phone", "en.portfolio.projects[3].link"]
+//
+// `set` runs before `remove`. A removed value becomes `null`, which leaves an
+// optional field empty instead of letting the fallback language fill it in.
+
+/// Directory of the overlay files inside the content directory
+pub const OVERLAYS_DIR: &str = "overlays";
+
+#[derive(Deserialize, Default)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct Overlay {
+    /// Values to write, by path
+    #[serde(default)]
+    #[cfg_attr(test, schemars(with = "BTreeMap<String, serde_json::Value>"))]
+    pub set: BTreeMap<String, Value>,
+    /// Paths of the values to clear
+    #[serde(default)]
+    pub remove: Vec<String>,
+}
+
+impl Overlay {
+    /// Apply to the raw document with every language, failing on a path that
+    /// matches nothing so a renamed field cannot silently leak into a build
+    pub fn apply(&self, raw: &mut Value) -> Result<(), String> {
+        for (path, value) in &self.set {
+            edit(raw, path, &mut |target| *target = value.clone())?;
+        }
+        for path in &self.remove {
+            edit(raw, path, &mut |target| *target = Value::Null)?;
+        }
+        Ok(())
+    }
+}
+
+/// Split a comma-separated list of overlay names, as in `CONTENT_OVERLAY`
+pub fn split_names(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Whether `name` can name an overlay file
+pub fn is_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+fn edit(raw: &mut Value, path: &str, apply: &mut dyn FnMut(&mut Value)) -> Result<(), String> {
+    let segments = parse(path).ok_or_else(|| format!("invalid path `{path}`"))?;
+    match visit(raw, &segments, apply) {
+        0 => Err(format!("`{path}` matches nothing")),
+        _ => Ok(()),
+    }
+}
+
+enum Segment<'a> {
+    Key(&'a str),
+    AnyKey,
+    Index(usize),
+    AnyIndex,
+}
+
+/// Split `*.portfolio.projects[*].link` into keys, list indices and wildcards
+fn parse(path: &str) -> Option<Vec<Segment<'_>>> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (key, mut indices) = part.split_once('[').unwrap_or((part, ""));
+        segments.push(match key {
+            "" => return None,
+            "*" => Segment::AnyKey,
+            key => Segment::Key(key),
+        });
+        while !indices.is_empty() {
+            let (index, rest) = indices.split_once(']')?;
+            segments.push(match index {
+                "*" => Segment::AnyIndex,
+                index => Segment::Index(index.parse().ok()?),
+            });
+            indices = match rest {
+                "" => "",
+                rest => rest.strip_prefix('[')?,
+            };
+        }
+    }
+    Some(segments)
+}
+
+/// Call `apply` on every value the path leads to and count them. The last key
+/// is added to a mapping that lacks it, since a translation may leave a field
+/// to its fallback language.
+fn visit(value: &mut Value, segments: &[Segment], apply: &mut dyn FnMut(&mut Value)) -> usize {
+    let Some((segment, rest)) = segments.split_first() else {
+        apply(value);
+        return 1;
+    };
+    match (segment, value) {
+        (Segment::Key(key), Value::Mapping(mapping)) => {
+            if rest.is_empty() && !mapping.contains_key(*key) {
+                mapping.insert(Value::from(*key), Value::Null);
+            }
+            mapping
+                .get_mut(*key)
+                .map_or(0, |value| visit(value, rest, apply))
+        }
+        (Segment::AnyKey, Value::Mapping(mapping)) => mapping
+            .values_mut()
+            .map(|value| visit(value, rest, apply))
+            .sum(),
+        (Segment::Index(index), Value::Sequence(items)) => items
+            .get_mut(*index)
+            .map_or(0, |value| visit(value, rest, apply)),
+        (Segment::AnyIndex, Value::Sequence(items)) => items
+            .iter_mut()
+            .map(|value| visit(value, rest, apply))
+            .sum(),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{CONTENT_YAML, Content, Month};
+
+    #[test]
+    fn sets_and_removes_values_in_every_language() {
+        let mut raw: Value = serde_yaml::from_str(CONTENT_YAML).unwrap();
+        let overlay: Overlay = serde_yaml::from_str(
+            "set: {'*.contact.email': public@itmage.ru}\n\
+             remove: ['*.contact.phone', 'en.about.work_experience[*].end']\n",
+        )
+        .unwrap();
+        overlay.apply(&mut raw).unwrap();
+
+        let merged = serde_yaml::to_string(&raw).unwrap();
+        let (content, _) = Content::from_yaml(&merged, Month::new(2025, 6).unwrap()).unwrap();
+        for (lang, content) in content.languages() {
+            assert_eq!(content.contact.email, "public@itmage.ru");
+            assert_eq!(content.contact.phone, None);
+            let ended = content
+                .about
+                .work_experience
+                .iter()
+                .filter(|exp| exp.end.is_some());
+            assert_eq!(ended.count() == 0, lang.as_str() == "en");
+        }
+    }
+
+    #[test]
+    fn rejects_paths_that_match_nothing() {
+        let mut raw: Value = serde_yaml::from_str(CONTENT_YAML).unwrap();
+        let apply = |overlay: &str, raw: &mut Value| {
+            let overlay: Overlay = serde_yaml::from_str(overlay).unwrap();
+            overlay.apply(raw).err().unwrap()
+        };
+        assert_eq!(
+            apply("remove: ['*.contacts.phone']", &mut raw),
+            "`*.contacts.phone` matches nothing"
+        );
+        assert_eq!(
+            apply("remove: ['ru.portfolio.projects[99].role']", &mut raw),
+            "`ru.portfolio.projects[99].role` matches nothing"
+        );
+        assert_eq!(
+            apply("remove: ['ru..phone']", &mut raw),
+            "invalid path `ru..phone`"
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use serde_yaml::Value;
+use std::fmt;
+
+// Translations are compared as written in the merged document, before the
+// fallback fills their gaps and work experience is sorted by date, so entries
+// missing from the end of a list or written in another order are reported
+// instead of hidden. Only language-neutral fields are compared, and only where
+// both languages set them: a field a translation leaves out comes from its
+// fallback anyway.
+
+/// A structural difference between a translation and the default language
+#[derive(Clone, PartialEq, Debug)]
+pub struct Mismatch {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Flags of entries that can be drafts
+const DRAFT: &[&str] = &["draft", "visible"];
+
+/// Compare every language of the merged document against the default one,
+/// the first in the document, and report all drift
+pub fn parity_report(raw: &Value) -> Vec<Mismatch> {
+    let Value::Mapping(languages) = raw else {
+        return Vec::new();
+    };
+    let mut languages = languages
+        .iter()
+        .filter_map(|(lang, content)| Some((lang.as_str()?, content)));
+    let Some((primary, base)) = languages.next() else {
+        return Vec::new();
+    };
+    languages
+        .flat_map(|(lang, content)| check_parity(primary, base, lang, content))
+        .collect()
+}
+
+/// Compare list lengths and language-neutral fields of two translations
+pub fn check_parity(
+    left_lang: &str,
+    left: &Value,
+    right_lang: &str,
+    right: &Value,
+) -> Vec<Mismatch> {
+    let mut report = Report {
+        left_lang,
+        right_lang,
+        mismatches: Vec::new(),
+    };
+
+    report.fields("", left, right, &["hero.logo", "about.photo"]);
+
+    report.list(
+        "about.work_experience",
+        get(left, "about.work_experience"),
+        get(right, "about.work_experience"),
+        |report, path, left, right| {
+            report.fields(path, left, right, DRAFT);
+            report.fields(path, left, right, &["start", "end"]);
+        },
+    );
+
+    report.list(
+        "portfolio.projects",
+        get(left, "portfolio.projects"),
+        get(right, "portfolio.projects"),
+        |report, path, left, right| {
+            report.fields(path, left, right, DRAFT);
+            report.fields(
+                path,
+                left,
+                right,
+                &[
+                    "link",
+                    "technologies",
+                    "period.start",
+                    "period.end",
+                    "cover.src",
+                ],
+            );
+            report.list(
+                &join(path, "screenshots"),
+                left.get("screenshots"),
+                right.get("screenshots"),
+                |report, path, left, right| report.fields(path, left, right, &["src"]),
+            );
+        },
+    );
+
+    report.list(
+        "portfolio.skill_categories",
+        get(left, "portfolio.skill_categories"),
+        get(right, "portfolio.skill_categories"),
+        |report, path, left, right| report.fields(path, left, right, &["id"]),
+    );
+
+    report.list(
+        "portfolio.skills",
+        get(left, "portfolio.skills"),
+        get(right, "portfolio.skills"),
+        |report, path, left, right| {
+            report.fields(path, left, right, DRAFT);
+            report.fields(
+                path,
+                left,
+                right,
+                &["category", "level", "since", "last_used", "technologies"],
+            );
+        },
+    );
+
+    report.fields("", left, right, &["contact.phone", "contact.email"]);
+
+    report.list(
+        "contact.social_networks",
+        get(left, "contact.social_networks"),
+        get(right, "contact.social_networks"),
+        |report, path, left, right| report.fields(path, left, right, &["url", "icon"]),
+    );
+
+    report.mismatches
+}
+
+struct Report<'a> {
+    left_lang: &'a str,
+    right_lang: &'a str,
+    mismatches: Vec<Mismatch>,
+}
+
+impl Report<'_> {
+    fn push(&mut self, path: &str, message: String) {
+        self.mismatches.push(Mismatch {
+            path: format!("{}.{path}", self.right_lang),
+            message,
+        });
+    }
+
+    /// Compare the values at `fields`, relative to the entries at `path`
+    fn fields(&mut self, path: &str, left: &Value, right: &Value, fields: &[&str]) {
+        for field in fields {
+            if let (Some(left), Some(right)) = (get(left, field), get(right, field))
+                && left != right
+            {
+                let message = format!(
+                    "{} has {}, {} has {}",
+                    self.left_lang,
+                    show(left),
+                    self.right_lang,
+                    show(right)
+                );
+                self.push(&join(path, field), message);
+            }
+        }
+    }
+
+    /// Compare list lengths, then the entries both lists have
+    fn list(
+        &mut self,
+        path: &str,
+        left: Option<&Value>,
+        right: Option<&Value>,
+        compare: impl Fn(&mut Self, &str, &Value, &Value),
+    ) {
+        let (Some(Value::Sequence(left)), Some(Value::Sequence(right))) = (left, right) else {
+            return;
+        };
+        if left.len() != right.len() {
+            let message = format!(
+                "{} has {} entries, {} has {}",
+                self.left_lang,
+                left.len(),
+                self.right_lang,
+                right.len()
+            );
+            self.push(path, message);
+        }
+        for (index, (left, right)) in left.iter().zip(right).enumerate() {
+            compare(self, &format!("{path}[{index}]"), left, right);
+        }
+    }
+}
+
+/// Value at a dotted path such as `contact.phone`
+fn get<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(value, |value, key| value.get(key))
+}
+
+fn join(path: &str, field: &str) -> String {
+    match path {
+        "" => field.to_owned(),
+        path => format!("{path}.{field}"),
+    }
+}
+
+/// A value as it would be written in the content file
+fn show(value: &Value) -> String {
+    match value {
+        Value::String(text) => format!("{text:?}"),
+        Value::Sequence(items) => {
+            let items: Vec<String> = items.iter().map(show).collect();
+            format!("[{}]", items.join(", "))
+        }
+        value => serde_yaml::to_string(value)
+            .map_or_else(|_| format!("{value:?}"), |text| text.trim_end().to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::CONTENT_YAML;
+
+    fn raw() -> Value {
+        serde_yaml::from_str(CONTENT_YAML).unwrap()
+    }
+
+    #[test]
+    fn embedded_languages_are_in_parity() {
+        let report = parity_report(&raw());
+        let lines: Vec<String> = report.iter().map(ToString::to_string).collect();
+        assert!(report.is_empty(), "content drift:\n{}", lines.join("\n"));
+    }
+
+    #[test]
+    fn reordered_and_missing_entries_are_reported_by_path() {
+        let mut raw = raw();
+        let en = &mut raw["en"];
+        let work = en["about"]["work_experience"].as_sequence_mut().unwrap();
+        work.swap(0, 1);
+        en["portfolio"]["skills"].as_sequence_mut().unwrap().pop();
+        // Left out, so filled from the fallback rather than drifting
+        en["contact"].as_mapping_mut().unwrap().remove("email");
+        en["contact"]["phone"] = Value::from("+7 000");
+
+        let report = parity_report(&raw);
+        let paths: Vec<&str> = report
+            .iter()
+            .map(|mismatch| mismatch.path.as_str())
+            .collect();
+
+        assert_eq!(
+            paths,
+            [
+                "en.about.work_experience[0].start",
+                "en.about.work_experience[1].start",
+                "en.portfolio.skills",
+                "en.contact.phone",
+            ]
+        );
+        assert_eq!(report[2].message, "ru has 8 entries, en has 7");
+        assert_eq!(
+            report[3].message,
+            "ru has \"+7 987 777 34 45\", en has \"+7 000\""
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use std::fmt;
+use std::str::FromStr;
+
+use super::sanitize::{Sanitized, link_target, looks_like_html, sanitize};
+
+/// Description text written in a small Markdown subset:
+/// `**strong**`, `*emphasis*`, `` `code` ``, `[link](url)` and `[[chip link]](url)`.
+/// A backslash escapes the next character. Link targets are limited to the
+/// sanitizer's safe schemes. Text containing HTML tags is passed through the
+/// allowlist sanitizer instead.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RichText(pub Vec<Inline>);
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Inline {
+    Text(String),
+    Strong(Vec<Inline>),
+    Emphasis(Vec<Inline>),
+    Code(String),
+    Link {
+        text: String,
+        url: String,
+    },
+    /// A link rendered as a highlighted button, e.g. a pet project name
+    Chip {
+        text: String,
+        url: String,
+    },
+    /// Pasted HTML that survived the sanitizer
+    Html(Sanitized),
+}
+
+impl RichText {
+    /// Text without markup, for exports that take plain strings
+    pub fn plain_text(&self) -> String {
+        let mut out = String::new();
+        push_plain(&mut out, &self.0);
+        out
+    }
+}
+
+fn push_plain(out: &mut String, nodes: &[Inline]) {
+    for node in nodes {
+        match node {
+            Inline::Text(text) | Inline::Code(text) => out.push_str(text),
+            Inline::Strong(inner) | Inline::Emphasis(inner) => push_plain(out, inner),
+            Inline::Link { text, .. } | Inline::Chip { text, .. } => out.push_str(text),
+            Inline::Html(sanitized) => out.push_str(&sanitized.text()),
+        }
+    }
+}
+
+impl FromStr for RichText {
+    type Err = String;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        if looks_like_html(source) {
+            return Ok(RichText(vec![Inline::Html(sanitize(source))]));
+        }
+        let mut parser = Parser { source, pos: 0 };
+        parser.inlines(None).map(RichText)
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        let found = self.rest().starts_with(token);
+        if found {
+            self.pos += token.len();
+        }
+        found
+    }
+
+    /// Parse inline nodes until `closing` or the end of input
+    fn inlines(&mut self, closing: Option<&str>) -> Result<Vec<Inline>, String> {
+        let mut nodes = Vec::new();
+        let mut text = String::new();
+
+        loop {
+            // `**` inside `*emphasis*` opens strong text rather than closing
+            let opens_strong = closing == Some("*") && self.rest().starts_with("**");
+            if let Some(closing) = closing
+                && !opens_strong
+                && self.eat(closing)
+            {
+                flush(&mut text, &mut nodes);
+                return Ok(nodes);
+            }
+
+            let Some(c) = self.rest().chars().next() else {
+                return match closing {
+                    Some(closing) => Err(format!("unclosed `{closing}`")),
+                    None => {
+                        flush(&mut text, &mut nodes);
+                        Ok(nodes)
+                    }
+                };
+            };
+
+            let node = if self.eat("\\") {
+                match self.rest().chars().next() {
+                    Some(escaped) => {
+                        text.push(escaped);
+                        self.pos += escaped.len_utf8();
+                    }
+                    None => text.push('\\'),
+                }
+                None
+            } else if self.eat("`") {
+                Some(Inline::Code(self.until("`")?.to_owned()))
+            } else if self.eat("**") {
+                Some(Inline::Strong(self.inlines(Some("**"))?))
+            } else if self.eat("*") {
+                Some(Inline::Emphasis(self.inlines(Some("*"))?))
+            } else if self.rest().starts_with("[[") {
+                self.pos += 2;
+                let text = self.until("]]")?.to_owned();
+                let url = self.url()?;
+                Some(Inline::Chip { text, url })
+            } else if let Some((link_text, url)) = self.link() {
+                link_target(&url)?;
+                Some(Inline::Link {
+                    text: link_text,
+                    url,
+                })
+            } else {
+                text.push(c);
+                self.pos += c.len_utf8();
+                None
+            };
+
+            if let Some(node) = node {
+                flush(&mut text, &mut nodes);
+                nodes.push(node);
+            }
+        }
+    }
+
+    /// Consume input up to and including `closing`, returning what came before it
+    fn until(&mut self, closing: &str) -> Result<&'a str, String> {
+        let rest = self.rest();
+        let end = rest
+            .find(closing)
+            .ok_or_else(|| format!("unclosed `{closing}`"))?;
+        self.pos += end + closing.len();
+        Ok(&rest[..end])
+    }
+
+    /// Parse `(url)` following link text
+    fn url(&mut self) -> Result<String, String> {
+        if !self.eat("(") {
+            return Err("expected `(url)` after link text".to_owned());
+        }
+        let url = self.until(")")?;
+        if url.is_empty() || url.contains(char::is_whitespace) {
+            return Err(format!("invalid link target `{url}`"));
+        }
+        link_target(url)?;
+        Ok(url.to_owned())
+    }
+
+    /// Parse `[text](url)`; a bracket not followed by a link target stays literal text
+    fn link(&mut self) -> Option<(String, String)> {
+        let rest = self.rest().strip_prefix('[')?;
+        let (text, after) = rest.split_once("](")?;
+        let (url, _) = after.split_once(')')?;
+        if text.contains(['[', ']']) || url.is_empty() || url.contains(char::is_whitespace) {
+            return None;
+        }
+        self.pos += 1 + text.len() + 2 + url.len() + 1;
+        Some((text.to_owned(), url.to_owned()))
+    }
+}
+
+fn flush(text: &mut String, nodes: &mut Vec<Inline>) {
+    if !text.is_empty() {
+        nodes.push(Inline::Text(std::mem::take(text)));
+    }
+}
+
+impl<'de> Deserialize<'de> for RichText {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RichTextVisitor;
+
+        impl Visitor<'_> for RichTextVisitor {
+            type Value = RichText;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("Markdown text")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<RichText, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(RichTextVisitor)
+    }
+}
+
+#[cfg(test)]
+impl schemars::JsonSchema for RichText {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "RichText".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Text with `**strong**`, `*emphasis*`, `` `code` ``, `[link](url)` and `[[chip link]](url)` markup",
+            "type": "string",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> Inline {
+        Inline::Text(value.to_owned())
+    }
+
+    #[test]
+    fn parses_inline_markup() {
+        let parsed: RichText = "[[Baza]](https://github.com/itmagelab/baza) — a **fast** `pass` *alternative*, see [docs](https://example.com)"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            parsed.0,
+            [
+                Inline::Chip {
+                    text: "Baza".to_owned(),
+                    url: "https://github.com/itmagelab/baza".to_owned(),
+                },
+                text(" — a "),
+                Inline::Strong(vec![text("fast")]),
+                text(" "),
+                Inline::Code("pass".to_owned()),
+                text(" "),
+                Inline::Emphasis(vec![text("alternative")]),
+                text(", see "),
+                Inline::Link {
+                    text: "docs".to_owned(),
+                    url: "https://example.com".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn drops_markup_in_plain_text() {
+        let parsed: RichText = "[[Baza]](https://x.org) is **fast** `pass`"
+            .parse()
+            .unwrap();
+        assert_eq!(parsed.plain_text(), "Baza is fast pass");
+        let html: RichText = "<strong>Kubcoin</strong> &mdash; ok".parse().unwrap();
+        assert_eq!(html.plain_text(), "Kubcoin — ok");
+    }
+
+    #[test]
+    fn keeps_markup_characters_literal_when_escaped_or_unmatched() {
+        let parsed: RichText = r"2\*3 [sic] a < b".parse().unwrap();
+        assert_eq!(parsed.0, [text("2*3 [sic] a < b")]);
+    }
+
+    #[test]
+    fn rejects_unclosed_markup() {
+        assert!("**bold".parse::<RichText>().is_err());
+        assert!("`code".parse::<RichText>().is_err());
+        assert!("[[Baza]] no link".parse::<RichText>().is_err());
+    }
+
+    #[test]
+    fn rejects_links_with_unsafe_schemes() {
+        let error = "[x](javascript:void)".parse::<RichText>().unwrap_err();
+        assert!(
+            error.starts_with("`javascript:void` must be a path"),
+            "{error}"
+        );
+        let yaml = "description:\n- ok\n- '[x](javascript:void)'\n";
+        let error = serde_yaml::from_str::<std::collections::BTreeMap<String, Vec<RichText>>>(yaml)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.starts_with("description[1]: `javascript:void`"),
+            "{error}"
+        );
+        assert!("[[x]](data:text/html,hi)".parse::<RichText>().is_err());
+        assert!("[x](http://example.com)".parse::<RichText>().is_err());
+        assert!(
+            "[x](/static/cv.pdf) [y](mailto:i@itmage.ru)"
+                .parse::<RichText>()
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,484 @@
+use super::markdown;
+use super::model::Content;
+use super::rich_text::{Inline, RichText};
+
+// Allowlist sanitizer for HTML pasted into descriptions. Only the tags and
+// attributes below survive, links are limited to safe schemes and links that
+// leave the site always get `rel="noopener noreferrer"`. Everything removed is
+// reported.
+
+const ALLOWED: &[(&str, &[&str])] = &[
+    ("a", &["href", "target", "rel", "class"]),
+    ("i", &["class"]),
+    ("strong", &[]),
+    ("em", &[]),
+];
+
+/// Tags whose content is dropped together with the tag
+const DROP_CONTENT: &[&str] = &["script", "style", "iframe", "object", "template"];
+
+/// Schemes links in the content may use, in descriptions and in URL fields alike
+pub const SAFE_SCHEMES: &[&str] = &["https", "mailto", "tel", "tg"];
+
+/// Where a link leads
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LinkTarget {
+    /// A path or fragment on the site, such as `/static/cv.pdf` or `#contact`
+    Site,
+    /// Off the site, with the lowercase scheme
+    External(&'static str),
+}
+
+/// Classify a link, rejecting schemes outside `SAFE_SCHEMES`. A protocol-relative
+/// `//host/path` leaves the site over the page's own scheme, which is `https`.
+pub fn link_target(href: &str) -> Result<LinkTarget, String> {
+    if href.starts_with("//") {
+        return Ok(LinkTarget::External("https"));
+    }
+    if href.starts_with('/') || href.starts_with('#') {
+        return Ok(LinkTarget::Site);
+    }
+    let scheme = href.split_once(':').map(|(scheme, _)| scheme);
+    match scheme.and_then(|scheme| {
+        SAFE_SCHEMES
+            .iter()
+            .find(|safe| safe.eq_ignore_ascii_case(scheme))
+    }) {
+        Some(scheme) => Ok(LinkTarget::External(scheme)),
+        None => Err(format!(
+            "`{href}` must be a path on the site or use one of the schemes {}",
+            SAFE_SCHEMES.join(", ")
+        )),
+    }
+}
+
+/// Sanitized HTML and a description of everything that was removed
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Sanitized {
+    pub html: String,
+    pub stripped: Vec<String>,
+}
+
+/// Whether `text` contains something that looks like an HTML tag or comment
+pub fn looks_like_html(text: &str) -> bool {
+    text.match_indices('<').any(|(index, _)| {
+        text[index + 1..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!')
+    })
+}
+
+/// Keep only allowlisted tags and attributes of an HTML fragment
+pub fn sanitize(source: &str) -> Sanitized {
+    let mut out = Sanitized::default();
+    let mut open: Vec<&str> = Vec::new();
+    let mut rest = source;
+
+    while let Some(start) = rest.find('<') {
+        push_text(&mut out.html, &rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(after) = rest.strip_prefix("<!--") {
+            let end = after.find("-->").map_or(after.len(), |end| end + 3);
+            out.stripped.push("comment".to_owned());
+            rest = &after[end..];
+            continue;
+        }
+
+        let Some((tag, after)) = Tag::parse(rest) else {
+            push_text(&mut out.html, "<");
+            rest = &rest[1..];
+            continue;
+        };
+        rest = after;
+
+        let allowed = ALLOWED.iter().find(|(name, _)| *name == tag.name);
+        match (allowed, tag.closing) {
+            (Some((name, _)), true) => {
+                if let Some(index) = open.iter().rposition(|open| open == name) {
+                    for name in open.drain(index..).rev() {
+                        out.html.push_str(&format!("</{name}>"));
+                    }
+                } else {
+                    out.stripped.push(format!("unmatched </{name}>"));
+                }
+            }
+            (Some((name, attributes)), false) => {
+                out.html.push_str(&format!("<{name}"));
+                push_attributes(&mut out, &tag, attributes);
+                out.html.push('>');
+                open.push(name);
+            }
+            (None, true) => {}
+            (None, false) => {
+                out.stripped.push(format!("<{}>", tag.name));
+                if DROP_CONTENT.contains(&tag.name.as_str()) {
+                    let closing = format!("</{}", tag.name);
+                    let end = find_ignore_case(rest, &closing).unwrap_or(rest.len());
+                    rest = &rest[end..];
+                }
+            }
+        }
+    }
+
+    push_text(&mut out.html, rest);
+    for name in open.into_iter().rev() {
+        out.html.push_str(&format!("</{name}>"));
+    }
+    out
+}
+
+impl Sanitized {
+    /// Text of the fragment without tags, character references decoded
+    pub fn text(&self) -> String {
+        let mut out = String::new();
+        let mut rest = self.html.as_str();
+        while let Some(start) = rest.find('<') {
+            out.push_str(&decode(&rest[..start]));
+            rest = Tag::parse(&rest[start..]).map_or(&rest[start + 1..], |(_, after)| after);
+        }
+        out.push_str(&decode(rest));
+        out
+    }
+
+    /// The fragment as Markdown: links, strong and emphasized text keep their
+    /// markup, other tags such as icons are dropped
+    pub fn markdown(&self) -> String {
+        let mut out = String::new();
+        // Targets of the open links, `None` for a link without `href`
+        let mut links: Vec<Option<String>> = Vec::new();
+        let mut rest = self.html.as_str();
+        while let Some(start) = rest.find('<') {
+            out.push_str(&markdown::escape(&decode(&rest[..start])));
+            let Some((tag, after)) = Tag::parse(&rest[start..]) else {
+                rest = &rest[start + 1..];
+                continue;
+            };
+            rest = after;
+            match (tag.name.as_str(), tag.closing) {
+                ("a", false) => {
+                    let href = tag
+                        .attributes
+                        .into_iter()
+                        .find(|(name, _)| name == "href")
+                        .map(|(_, href)| decode(&href));
+                    if href.is_some() {
+                        out.push('[');
+                    }
+                    links.push(href);
+                }
+                ("a", true) => {
+                    if let Some(Some(href)) = links.pop() {
+                        out.push_str(&format!("]({href})"));
+                    }
+                }
+                ("strong", _) => out.push_str("**"),
+                ("em", _) => out.push('*'),
+                _ => {}
+            }
+        }
+        out.push_str(&markdown::escape(&decode(rest)));
+        out
+    }
+}
+
+/// Named references decoded to text, other names are kept as written
+const ENTITIES: &[(&str, char)] = &[
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("nbsp", '\u{a0}'),
+    ("ndash", '–'),
+    ("mdash", '—'),
+    ("laquo", '«'),
+    ("raquo", '»'),
+    ("hellip", '…'),
+];
+
+/// Replace character references such as `&amp;` and `&#8212;` with the characters
+fn decode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest[1..].split_once(';').and_then(|(name, _)| {
+            let c = match name.strip_prefix('#') {
+                Some(number) => match number.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => number.parse().ok(),
+                }
+                .and_then(char::from_u32),
+                None => ENTITIES
+                    .iter()
+                    .find(|(entity, _)| *entity == name)
+                    .map(|(_, c)| *c),
+            };
+            c.map(|c| (c, name.len() + 2))
+        });
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn push_attributes(out: &mut Sanitized, tag: &Tag, allowed: &[&str]) {
+    let mut external = false;
+    for (name, value) in &tag.attributes {
+        if !allowed.contains(&name.as_str()) {
+            out.stripped
+                .push(format!("attribute `{name}` on <{}>", tag.name));
+            continue;
+        }
+        match name.as_str() {
+            // Always forced below for external links
+            "rel" => continue,
+            "href" => {
+                let href = value.trim();
+                match link_target(href) {
+                    Ok(target) => external = target != LinkTarget::Site,
+                    Err(_) => {
+                        out.stripped
+                            .push(format!("href `{href}` on <{}>", tag.name));
+                        continue;
+                    }
+                }
+            }
+            _ => {}
+        }
+        out.html.push_str(&format!(" {name}=\"{}\"", escape(value)));
+    }
+    if external {
+        out.html.push_str(" rel=\"noopener noreferrer\"");
+    }
+}
+
+struct Tag {
+    name: String,
+    closing: bool,
+    attributes: Vec<(String, String)>,
+}
+
+impl Tag {
+    /// Parse a tag at the start of `source`, returning it and the remaining input
+    fn parse(source: &str) -> Option<(Tag, &str)> {
+        let mut rest = source.strip_prefix('<')?;
+        let closing = rest.starts_with('/');
+        if closing {
+            rest = &rest[1..];
+        }
+
+        let name_len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        if name_len == 0 || !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let name = rest[..name_len].to_ascii_lowercase();
+        rest = &rest[name_len..];
+
+        let mut attributes = Vec::new();
+        loop {
+            rest = rest.trim_start();
+            if let Some(after) = rest.strip_prefix("/>").or_else(|| rest.strip_prefix('>')) {
+                let tag = Tag {
+                    name,
+                    closing,
+                    attributes,
+                };
+                return Some((tag, after));
+            }
+
+            let attr_len = rest
+                .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+                .unwrap_or(rest.len());
+            if attr_len == 0 {
+                return None;
+            }
+            let attr = rest[..attr_len].to_ascii_lowercase();
+            rest = rest[attr_len..].trim_start();
+
+            let value = match rest.strip_prefix('=') {
+                Some(after) => {
+                    let after = after.trim_start();
+                    let (value, after) = match after.chars().next()? {
+                        quote @ ('"' | '\'') => {
+                            let end = after[1..].find(quote)?;
+                            (&after[1..=end], &after[end + 2..])
+                        }
+                        _ => {
+                            let end = after
+                                .find(|c: char| c.is_whitespace() || c == '>')
+                                .unwrap_or(after.len());
+                            (&after[..end], &after[end..])
+                        }
+                    };
+                    rest = after;
+                    value.to_owned()
+                }
+                None => String::new(),
+            };
+            attributes.push((attr, value));
+        }
+    }
+}
+
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .to_ascii_lowercase()
+        .find(&needle.to_ascii_lowercase())
+}
+
+/// Escape text, keeping well-formed character references such as `&mdash;`
+fn push_text(out: &mut String, text: &str) {
+    let mut rest = text;
+    while let Some(index) = rest.find(['&', '<', '>', '"']) {
+        out.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if rest.starts_with('&') && is_reference(rest) {
+            out.push('&');
+        } else {
+            out.push_str(escape(&rest[..1]).as_str());
+        }
+        rest = &rest[1..];
+    }
+    out.push_str(rest);
+}
+
+fn is_reference(text: &str) -> bool {
+    let Some(end) = text.find(';') else {
+        return false;
+    };
+    let name = &text[1..end];
+    match name.strip_prefix('#') {
+        Some(number) => {
+            let digits = number
+                .strip_prefix(['x', 'X'])
+                .map_or(number.chars().all(|c| c.is_ascii_digit()), |hex| {
+                    hex.chars().all(|c| c.is_ascii_hexdigit())
+                });
+            !number.is_empty() && digits
+        }
+        None => !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric()),
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl Content {
+    /// Everything the sanitizer removed from descriptions, with content paths
+    pub fn sanitizer_report(&self) -> Vec<String> {
+        let mut report = Vec::new();
+        for (lang, content) in self.languages() {
+            for (i, exp) in content.about.work_experience.iter().enumerate() {
+                let path = format!("{lang}.about.work_experience[{i}].description");
+                report_texts(&mut report, &path, &exp.description);
+            }
+            for (i, project) in content.portfolio.projects.iter().enumerate() {
+                let path = format!("{lang}.portfolio.projects[{i}].description");
+                report_texts(&mut report, &path, &project.description);
+            }
+        }
+        report
+    }
+}
+
+fn report_texts(report: &mut Vec<String>, path: &str, texts: &[RichText]) {
+    for (index, text) in texts.iter().enumerate() {
+        for node in &text.0 {
+            if let Inline::Html(sanitized) = node {
+                for stripped in &sanitized.stripped {
+                    report.push(format!("{path}[{index}]: stripped {stripped}"));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_allowlisted_markup_and_forces_rel() {
+        let sanitized = sanitize(
+            r#"<a href="https://kubcoin.ru" target="_blank" rel="opener" class="chip"><i class="fas fa-link"></i>Kubcoin</a> &mdash; <strong>ok</strong>"#,
+        );
+
+        assert_eq!(
+            sanitized.html,
+            r#"<a href="https://kubcoin.ru" target="_blank" class="chip" rel="noopener noreferrer"><i class="fas fa-link"></i>Kubcoin</a> &mdash; <strong>ok</strong>"#
+        );
+        assert!(sanitized.stripped.is_empty());
+    }
+
+    #[test]
+    fn strips_and_reports_everything_else() {
+        let sanitized = sanitize(
+            r#"<p onclick="x()">Hi<script>alert(1)</script> <a href="javascript:alert(1)" style="color:red">link</a> <em>open"#,
+        );
+
+        assert_eq!(sanitized.html, "Hi <a>link</a> <em>open</em>");
+        assert_eq!(
+            sanitized.stripped,
+            [
+                "<p>",
+                "<script>",
+                "href `javascript:alert(1)` on <a>",
+                "attribute `style` on <a>",
+            ]
+        );
+    }
+
+    #[test]
+    fn treats_protocol_relative_links_as_external() {
+        let sanitized = sanitize(
+            r#"<a href="//example.com">a</a> <a href="http://example.com">b</a> <a href="/static/cv.pdf">c</a> <a href="TEL:+79877773445">d</a>"#,
+        );
+
+        assert_eq!(
+            sanitized.html,
+            r#"<a href="//example.com" rel="noopener noreferrer">a</a> <a>b</a> <a href="/static/cv.pdf">c</a> <a href="TEL:+79877773445" rel="noopener noreferrer">d</a>"#
+        );
+        assert_eq!(sanitized.stripped, ["href `http://example.com` on <a>"]);
+        assert_eq!(
+            link_target("//example.com"),
+            Ok(LinkTarget::External("https"))
+        );
+        assert_eq!(link_target("#contact"), Ok(LinkTarget::Site));
+        assert!(link_target("docs/index.html").is_err());
+    }
+
+    #[test]
+    fn extracts_text_with_references_decoded() {
+        let sanitized = sanitize(
+            r#"<a href="https://kubcoin.ru"><i class="x"></i>Kubcoin</a> &mdash; a &lt; b &amp;c &#8470;1 &unknown;"#,
+        );
+        assert_eq!(sanitized.text(), "Kubcoin — a < b &c №1 &unknown;");
+    }
+
+    #[test]
+    fn escapes_stray_angle_brackets() {
+        assert_eq!(sanitize("a < b & c").html, "a &lt; b &amp; c");
+        assert!(!looks_like_html("a < b"));
+        assert!(looks_like_html("a <b>c</b>"));
+    }
+}
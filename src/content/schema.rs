@@ -0,0 +1,92 @@
+use schemars::{Schema, schema_for};
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use super::lang::Lang;
+use super::model::{
+    AboutContent, ContactContent, FooterContent, HeroContent, LanguageInfo, PortfolioContent,
+};
+use super::overlay::Overlay;
+
+// JSON Schemas for the content files, generated from the model types and
+// committed under `schema/` so YAML language servers can autocomplete and
+// validate content. Each content file points at its schema with a
+// `# yaml-language-server: $schema=...` comment. A translation with a fallback
+// language may leave out any field, so its files point at the copies under
+// `schema/translation/`, which require nothing.
+
+/// Schema of every content file, keyed by its path under `schema/`
+pub fn schemas() -> Vec<(String, Schema)> {
+    let mut languages = schema_for!(Vec<Lang>);
+    languages.insert("minItems".to_owned(), 1.into());
+    languages.insert("uniqueItems".to_owned(), true.into());
+
+    let sections = [
+        ("language.json", schema_for!(LanguageInfo)),
+        ("hero.json", schema_for!(HeroContent)),
+        ("about.json", schema_for!(AboutContent)),
+        ("portfolio.json", schema_for!(PortfolioContent)),
+        ("contact.json", schema_for!(ContactContent)),
+        ("footer.json", schema_for!(FooterContent)),
+    ];
+    let translations: Vec<(String, Schema)> = sections
+        .iter()
+        .map(|(name, schema)| {
+            let mut value = schema.clone().to_value();
+            drop_required(&mut value);
+            let schema = Schema::try_from(value).expect("still an object schema");
+            (format!("translation/{name}"), schema)
+        })
+        .collect();
+
+    [("languages.json", languages)]
+        .into_iter()
+        .chain(sections)
+        .chain([("overlay.json", schema_for!(Overlay))])
+        .map(|(name, schema)| (name.to_owned(), schema))
+        .chain(translations)
+        .collect()
+}
+
+/// Remove the lists of required fields from a schema and every schema in it
+fn drop_required(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            if object.get("required").is_some_and(Value::is_array) {
+                object.remove("required");
+            }
+            object.values_mut().for_each(drop_required);
+        }
+        Value::Array(items) => items.iter_mut().for_each(drop_required),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn committed_schemas_are_up_to_date() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("schema");
+        let update = env::var_os("UPDATE_SCHEMA").is_some();
+
+        for (name, schema) in schemas() {
+            let path = dir.join(name);
+            let generated = serde_json::to_string_pretty(&schema).unwrap() + "\n";
+            if update {
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(&path, generated).unwrap();
+                continue;
+            }
+            let committed = fs::read_to_string(&path).unwrap_or_default();
+            assert!(
+                committed == generated,
+                "{} is stale, regenerate it with `UPDATE_SCHEMA=1 cargo test`",
+                path.display()
+            );
+        }
+    }
+}
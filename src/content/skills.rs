@@ -0,0 +1,295 @@
+use serde::Deserialize;
+use std::cmp::Reverse;
+
+use super::date::Duration;
+use super::filter::SEPARATOR;
+use super::lang::Lang;
+use super::model::{PortfolioContent, Skill, SkillCategory, SkillLevel, SkillLevels};
+
+/// How well a skill is mastered, from weakest to strongest
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(test, derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum Proficiency {
+    Beginner,
+    Intermediate,
+    Advanced,
+    Expert,
+}
+
+impl Proficiency {
+    /// Every level, weakest first, in the order the legend lists them
+    pub const ALL: [Proficiency; 4] = [
+        Proficiency::Beginner,
+        Proficiency::Intermediate,
+        Proficiency::Advanced,
+        Proficiency::Expert,
+    ];
+}
+
+impl SkillLevels {
+    /// Localized name and description of a level
+    pub fn get(&self, level: Proficiency) -> &SkillLevel {
+        match level {
+            Proficiency::Beginner => &self.beginner,
+            Proficiency::Intermediate => &self.intermediate,
+            Proficiency::Advanced => &self.advanced,
+            Proficiency::Expert => &self.expert,
+        }
+    }
+}
+
+impl Skill {
+    /// Years between the first and the last use, counting a skill in use up to
+    /// `current_year` and anything shorter as one year, `None` without `since`
+    pub fn years(&self, current_year: u16) -> Option<u32> {
+        let until = self.last_used.unwrap_or(current_year);
+        let since = self.since?;
+        Some(u32::from(until.saturating_sub(since)).max(1))
+    }
+
+    /// Years of use as a duration, for formatting with `DateLabels::duration`
+    pub fn duration(&self, current_year: u16) -> Option<Duration> {
+        Some(Duration {
+            years: self.years(current_year)?,
+            months: 0,
+        })
+    }
+}
+
+impl PortfolioContent {
+    /// Skills grouped by category in the order categories are declared, empty
+    /// categories left out. Within a group the strongest skills come first,
+    /// then the ones used the longest, then by name; unrated skills and those
+    /// without `since` go last.
+    pub fn skills_by_category(&self) -> Vec<(&SkillCategory, Vec<&Skill>)> {
+        self.skill_categories
+            .iter()
+            .filter_map(|category| {
+                let mut skills: Vec<&Skill> = self
+                    .skills
+                    .iter()
+                    .filter(|skill| skill.category == category.id)
+                    .collect();
+                skills.sort_by_key(|skill| {
+                    (
+                        Reverse(skill.level),
+                        skill.since.unwrap_or(u16::MAX),
+                        Reverse(skill.last_used.unwrap_or(u16::MAX)),
+                        skill.name.as_str(),
+                    )
+                });
+                (!skills.is_empty()).then_some((category, skills))
+            })
+            .collect()
+    }
+
+    /// Name of a skill followed by its level when rated, e.g. `Rust (Expert)`
+    pub fn skill_label(&self, skill: &Skill) -> String {
+        match skill.level {
+            Some(level) => format!("{} ({})", skill.name, self.skill_levels.get(level).name),
+            None => skill.name.clone(),
+        }
+    }
+}
+
+/// Check that category ids are unique, every skill names a declared category,
+/// no skill was last used before it was first used, and technology names can
+/// be used as filters: free of the filter separator and, for skills, used by
+/// some project
+pub fn check_skills(lang: &Lang, portfolio: &PortfolioContent) -> Result<(), String> {
+    for (index, project) in portfolio.projects.iter().enumerate() {
+        let reserved = project
+            .technologies
+            .iter()
+            .position(|technology| technology.contains(SEPARATOR));
+        if let Some(position) = reserved {
+            return Err(format!(
+                "{lang}.portfolio.projects[{index}].technologies[{position}]: `{SEPARATOR}` is not allowed in a technology"
+            ));
+        }
+    }
+
+    let categories = &portfolio.skill_categories;
+    for (index, category) in categories.iter().enumerate() {
+        if categories[..index]
+            .iter()
+            .any(|other| other.id == category.id)
+        {
+            return Err(format!(
+                "{lang}.portfolio.skill_categories[{index}].id: duplicate category `{}`",
+                category.id
+            ));
+        }
+    }
+
+    for (index, skill) in portfolio.skills.iter().enumerate() {
+        if !categories
+            .iter()
+            .any(|category| category.id == skill.category)
+        {
+            return Err(format!(
+                "{lang}.portfolio.skills[{index}].category: unknown category `{}`",
+                skill.category
+            ));
+        }
+        if let (Some(since), Some(last_used)) = (skill.since, skill.last_used)
+            && last_used < since
+        {
+            return Err(format!(
+                "{lang}.portfolio.skills[{index}].last_used: before `since`"
+            ));
+        }
+        let unused = skill.technologies.iter().position(|technology| {
+            !portfolio.projects.iter().any(|project| {
+                project
+                    .technologies
+                    .iter()
+                    .any(|used| used.eq_ignore_ascii_case(technology))
+            })
+        });
+        if let Some(position) = unused {
+            return Err(format!(
+                "{lang}.portfolio.skills[{index}].technologies[{position}]: no project uses `{}`",
+                skill.technologies[position]
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::{Content, Month};
+
+    const PORTFOLIO: &str = r#"
+ru:
+  language:
+    name: Русский
+    dates:
+      present: н.в.
+      month_names: [янв, фев, мар, апр, май, июн, июл, авг, сен, окт, ноя, дек]
+      years: [год, года, лет]
+      months: [месяц, месяца, месяцев]
+  hero: { title: T, subtitle: S }
+  about:
+    photo: /static/photo.jpg
+    title: T
+    description: D
+    skills: []
+    work_experience_title: W
+    work_experience: []
+    cv: { title: C, json_resume: J, pdf: D, markdown: M, copied: K, print: P, back: B }
+  portfolio:
+    title: T
+    projects_title: P
+    skills_title: S
+    projects: []
+    gallery: { open: O, close: C, previous: P, next: N }
+    filter: { active: A, clear: C, empty: E }
+    skill_categories:
+      - { id: languages, name: Языки }
+      - { id: empty, name: Пусто }
+      - { id: infrastructure, name: Инфраструктура }
+    skill_levels:
+      title: L
+      beginner: { name: B, description: "" }
+      intermediate: { name: I, description: "" }
+      advanced: { name: A, description: "" }
+      expert: { name: E, description: "" }
+    last_used_label: до
+    skills:
+      - { name: Ruby, category: languages, level: advanced, since: 2012 }
+      - { name: Linux, category: infrastructure, level: expert, since: 2009 }
+      - { name: Perl, category: languages, level: advanced, since: 2009, last_used: 2015 }
+      - { name: Rust, category: languages, level: expert, since: 2020 }
+      - { name: Bash, category: languages, level: advanced, since: 2009 }
+      - { name: Go, category: languages }
+  contact:
+    title: T
+    phone: "+79877773445"
+    phone_label: P
+    email: i@itmage.ru
+    email_label: E
+    save_contact: S
+    scan_contact: Q
+    social_title: S
+    social_networks: []
+  footer: { copyright: C, credits: C }
+"#;
+
+    fn portfolio(source: &str) -> Result<PortfolioContent, String> {
+        let (content, _) = Content::from_yaml(source, Month::new(2025, 6).unwrap())
+            .map_err(|err| err.to_string())?;
+        let ru: Lang = "ru".parse().unwrap();
+        Ok(content.get_or_default(&ru).portfolio.clone())
+    }
+
+    #[test]
+    fn groups_in_category_order_strongest_first() {
+        let portfolio = portfolio(PORTFOLIO).unwrap();
+        let groups: Vec<(&str, Vec<&str>)> = portfolio
+            .skills_by_category()
+            .into_iter()
+            .map(|(category, skills)| {
+                let names = skills.iter().map(|skill| skill.name.as_str()).collect();
+                (category.id.as_str(), names)
+            })
+            .collect();
+
+        assert_eq!(
+            groups,
+            [
+                ("languages", vec!["Rust", "Bash", "Perl", "Ruby", "Go"]),
+                ("infrastructure", vec!["Linux"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn counts_years_up_to_the_last_use() {
+        let portfolio = portfolio(PORTFOLIO).unwrap();
+        let years: Vec<Option<u32>> = portfolio
+            .skills
+            .iter()
+            .map(|skill| skill.years(2025))
+            .collect();
+        assert_eq!(
+            years,
+            [Some(13), Some(16), Some(6), Some(5), Some(16), None]
+        );
+        assert_eq!(portfolio.skills[3].years(2020), Some(1));
+        assert!(Proficiency::Expert > Proficiency::Advanced);
+        assert_eq!(portfolio.skill_levels.get(Proficiency::Expert).name, "E");
+        assert_eq!(portfolio.skill_label(&portfolio.skills[3]), "Rust (E)");
+        assert_eq!(portfolio.skill_label(&portfolio.skills[5]), "Go");
+    }
+
+    #[test]
+    fn rejects_unknown_categories_and_reversed_years() {
+        let unknown = PORTFOLIO.replacen("category: languages", "category: lang", 1);
+        assert_eq!(
+            portfolio(&unknown).err().unwrap(),
+            "ru.portfolio.skills[0].category: unknown category `lang`"
+        );
+
+        let reversed = PORTFOLIO.replacen("last_used: 2015", "last_used: 2005", 1);
+        assert_eq!(
+            portfolio(&reversed).err().unwrap(),
+            "ru.portfolio.skills[2].last_used: before `since`"
+        );
+
+        let unused = PORTFOLIO.replacen("since: 2020 }", "since: 2020, technologies: [Rust] }", 1);
+        assert_eq!(
+            portfolio(&unused).err().unwrap(),
+            "ru.portfolio.skills[3].technologies[0]: no project uses `Rust`"
+        );
+
+        let duplicate = PORTFOLIO.replacen("id: empty", "id: languages", 1);
+        assert_eq!(
+            portfolio(&duplicate).err().unwrap(),
+            "ru.portfolio.skill_categories[1].id: duplicate category `languages`"
+        );
+    }
+}
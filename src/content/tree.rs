@@ -0,0 +1,499 @@
+use serde::de::{DeserializeOwned, DeserializeSeed};
+use serde_yaml::{Mapping, Value};
+use std::cell::RefCell;
+use std::fs;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::date::Month;
+use super::draft;
+use super::fallback::{Fallback, FilledSeed, Substitution};
+use super::lang::Lang;
+use super::model::{
+    AboutContent, ContactContent, Content, FooterContent, HeroContent, LanguageInfo,
+    PortfolioContent, fallback_for,
+};
+use super::overlay::{OVERLAYS_DIR, Overlay, is_name};
+
+// Content is edited as one file per language and section, e.g.
+// `content/en/portfolio.yaml`, so that each file maps onto a single struct.
+// Every file is checked on its own to get errors with its name, line and
+// column, then the tree is merged into the single document that is embedded
+// into the site and parsed with `Content::from_yaml`. Overlays selected for
+// the build are applied to the merged document last, then release builds
+// strip the drafts.
+
+/// File listing the language directories, the first one is the default language
+pub const LANGUAGES_FILE: &str = "languages.yaml";
+
+/// Section files of a language directory, named after the `LangContent` fields they fill
+pub const SECTIONS: &[&str] = &[
+    "language",
+    "hero",
+    "about",
+    "portfolio",
+    "contact",
+    "footer",
+];
+
+/// How to prepare the content for a build
+#[derive(Default)]
+pub struct Options {
+    /// Overlays to apply, in order
+    pub overlays: Vec<String>,
+    /// Leave out draft entries, as release builds do
+    pub strip_drafts: bool,
+}
+
+/// Content assembled from a directory tree
+pub struct ContentTree {
+    pub content: Content,
+    pub substitutions: Vec<Substitution>,
+    /// Every file the content was read from, starting with the language list
+    /// and ending with the applied overlays
+    pub files: Vec<PathBuf>,
+    /// All languages merged into one YAML document
+    pub merged: String,
+}
+
+struct SectionFile {
+    lang: Lang,
+    section: &'static str,
+    path: PathBuf,
+    source: String,
+}
+
+/// Load, validate and merge the content directory at `root`, then prepare it
+/// as `options` ask
+pub fn load(root: &Path, options: &Options) -> Result<ContentTree, String> {
+    let languages = read_languages(root)?;
+
+    let mut raw = Mapping::new();
+    let mut files = Vec::new();
+    for lang in &languages {
+        let dir = root.join(lang.as_str());
+        let mut sections = Mapping::new();
+        for section in SECTIONS {
+            let path = dir.join(format!("{section}.yaml"));
+            if !path.exists() {
+                continue;
+            }
+            let source = read(&path)?;
+            let value: Value = serde_yaml::from_str(&source).map_err(|err| located(&path, &err))?;
+            sections.insert(Value::from(*section), value);
+            files.push(SectionFile {
+                lang: lang.clone(),
+                section,
+                path,
+                source,
+            });
+        }
+        raw.insert(Value::from(lang.as_str()), Value::Mapping(sections));
+    }
+    let raw = Value::Mapping(raw);
+
+    // Filled values are only needed for the checks, the merged parse logs substitutions
+    let log = RefCell::new(Vec::new());
+    for lang in &languages {
+        let fallback = fallback_for(&raw, lang)
+            .map_err(|err| format!("{}: {err}", section_path(root, lang, "language").display()))?;
+        if fallback.is_none() {
+            let missing = SECTIONS
+                .iter()
+                .map(|section| section_path(root, lang, section))
+                .find(|path| !path.exists());
+            if let Some(path) = missing {
+                return Err(format!(
+                    "{}: missing, and `{lang}` declares no fallback language",
+                    path.display()
+                ));
+            }
+        }
+
+        for file in files.iter().filter(|file| file.lang == *lang) {
+            let fallback = fallback.as_ref().and_then(|(from, value)| {
+                value.get(file.section).map(|value| {
+                    let path = format!("{lang}.{}", file.section);
+                    Fallback::new(value, path, from.clone(), &log)
+                })
+            });
+            check_section(file.section, &file.source, fallback)
+                .map_err(|err| located(&file.path, &err))?;
+        }
+    }
+
+    let today = today()?;
+    let merged = serde_yaml::to_string(&raw).map_err(|err| format!("{}: {err}", root.display()))?;
+    let (content, substitutions) = Content::from_yaml(&merged, today).map_err(|err| {
+        // Cross-field checks run on the merged document, point at the section file instead
+        let message = err.to_string();
+        files
+            .iter()
+            .find(|file| message.starts_with(&format!("{}.{}", file.lang, file.section)))
+            .map_or_else(
+                || format!("{}: {message}", root.display()),
+                |file| format!("{}: {message}", file.path.display()),
+            )
+    })?;
+    let mut files: Vec<PathBuf> = std::iter::once(root.join(LANGUAGES_FILE))
+        .chain(files.into_iter().map(|file| file.path))
+        .collect();
+
+    let mut raw = raw;
+    let mut changes = Vec::new();
+    for name in &options.overlays {
+        if !is_name(name) {
+            return Err(format!(
+                "invalid overlay name `{name}`, use lowercase letters, digits and `-`"
+            ));
+        }
+        let path = overlay_path(root, name);
+        let source = read(&path)?;
+        let overlay: Overlay = serde_yaml::from_str(&source).map_err(|err| located(&path, &err))?;
+        overlay
+            .apply(&mut raw)
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        changes.push(path.display().to_string());
+        files.push(path);
+    }
+    if options.strip_drafts && draft::strip(&mut raw) {
+        changes.push(format!("{} without drafts", root.display()));
+    }
+    if changes.is_empty() {
+        return Ok(ContentTree {
+            content,
+            substitutions,
+            files,
+            merged,
+        });
+    }
+
+    // The content was valid before, so whatever breaks now is the changes' doing
+    let merged = serde_yaml::to_string(&raw).map_err(|err| format!("{}: {err}", root.display()))?;
+    let (content, substitutions) = Content::from_yaml(&merged, today)
+        .map_err(|err| format!("{}: {err}", changes.join(", ")))?;
+    Ok(ContentTree {
+        content,
+        substitutions,
+        files,
+        merged,
+    })
+}
+
+/// Read the language list and make sure it matches the language directories
+fn read_languages(root: &Path) -> Result<Vec<Lang>, String> {
+    let path = root.join(LANGUAGES_FILE);
+    let source = read(&path)?;
+    let languages: Vec<Lang> = serde_yaml::from_str(&source).map_err(|err| located(&path, &err))?;
+    if languages.is_empty() {
+        return Err(format!(
+            "{}: at least one language is required",
+            path.display()
+        ));
+    }
+
+    for (index, lang) in languages.iter().enumerate() {
+        if languages[..index].contains(lang) {
+            return Err(format!("{}: duplicate language `{lang}`", path.display()));
+        }
+        if !root.join(lang.as_str()).is_dir() {
+            return Err(format!(
+                "{}: directory for language `{lang}` is missing",
+                path.display()
+            ));
+        }
+    }
+
+    for entry in entries(root)? {
+        let name = entry
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        if entry.is_dir() && name == OVERLAYS_DIR {
+            check_overlay_files(&entry)?;
+        } else if entry.is_dir() {
+            if !languages.iter().any(|lang| lang.as_str() == name) {
+                return Err(format!(
+                    "{}: language is not listed in {}",
+                    entry.display(),
+                    path.display()
+                ));
+            }
+            check_section_files(&entry)?;
+        } else if name != LANGUAGES_FILE {
+            return Err(format!("{}: unexpected file", entry.display()));
+        }
+    }
+
+    Ok(languages)
+}
+
+/// Reject files that would be silently ignored, such as a misspelled section
+fn check_section_files(dir: &Path) -> Result<(), String> {
+    for path in entries(dir)? {
+        let known = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(".yaml"))
+            .is_some_and(|section| SECTIONS.contains(&section));
+        if !known {
+            return Err(format!(
+                "{}: unknown section file, expected one of {}",
+                path.display(),
+                SECTIONS.join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Names of the overlays in the content directory at `root`
+pub fn overlays(root: &Path) -> Result<Vec<String>, String> {
+    let dir = root.join(OVERLAYS_DIR);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    check_overlay_files(&dir)?;
+    Ok(entries(&dir)?
+        .iter()
+        .filter_map(|path| path.file_stem()?.to_str().map(str::to_owned))
+        .collect())
+}
+
+fn check_overlay_files(dir: &Path) -> Result<(), String> {
+    for path in entries(dir)? {
+        let named = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(".yaml"))
+            .is_some_and(is_name);
+        if !named {
+            return Err(format!(
+                "{}: overlay files are named `<name>.yaml` with lowercase letters, digits and `-`",
+                path.display()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Deserialize a section file into the struct it maps onto
+fn check_section(
+    section: &str,
+    source: &str,
+    fallback: Option<Fallback>,
+) -> Result<(), serde_yaml::Error> {
+    match section {
+        "language" => check::<LanguageInfo>(source, fallback),
+        "hero" => check::<HeroContent>(source, fallback),
+        "about" => check::<AboutContent>(source, fallback),
+        "portfolio" => check::<PortfolioContent>(source, fallback),
+        "contact" => check::<ContactContent>(source, fallback),
+        "footer" => check::<FooterContent>(source, fallback),
+        _ => unreachable!("unknown section `{section}`"),
+    }
+}
+
+fn check<T: DeserializeOwned>(
+    source: &str,
+    fallback: Option<Fallback>,
+) -> Result<(), serde_yaml::Error> {
+    let deserializer = serde_yaml::Deserializer::from_str(source);
+    match fallback {
+        Some(fallback) => FilledSeed {
+            seed: PhantomData::<T>,
+            fallback,
+        }
+        .deserialize(deserializer)
+        .map(drop),
+        None => T::deserialize(deserializer).map(drop),
+    }
+}
+
+fn section_path(root: &Path, lang: &Lang, section: &str) -> PathBuf {
+    root.join(lang.as_str()).join(format!("{section}.yaml"))
+}
+
+/// File of the overlay `name` in the content directory at `root`
+pub fn overlay_path(root: &Path, name: &str) -> PathBuf {
+    root.join(OVERLAYS_DIR).join(format!("{name}.yaml"))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Directory entries sorted by name, so errors are reported in a stable order
+fn entries(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = fs::read_dir(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+    let mut paths = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("{}: {err}", dir.display()))?;
+    paths.sort();
+    Ok(paths)
+}
+
+/// Current month from the host clock, the site itself uses the browser's
+pub fn today() -> Result<Month, String> {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|err| err.to_string())?;
+    let days = i64::try_from(elapsed.as_secs() / 86_400).map_err(|err| err.to_string())?;
+    Month::from_unix_days(days).ok_or_else(|| "system clock is out of range".to_owned())
+}
+
+/// Format a YAML error as `file:line:column: message`
+pub fn located(path: &Path, err: &serde_yaml::Error) -> String {
+    let path = path.display();
+    match err.location() {
+        Some(location) => {
+            let (line, column) = (location.line(), location.column());
+            let message = err.to_string();
+            let suffix = format!(" at line {line} column {column}");
+            let message = message.strip_suffix(&suffix).unwrap_or(&message);
+            format!("{path}:{line}:{column}: {message}")
+        }
+        None => format!("{path}: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::overlay::split_names;
+    use crate::content::{CONTENT_YAML, OVERLAYS};
+
+    /// Copy the real content tree into a scratch directory for a test to modify
+    fn scratch_tree(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("content-tree-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("content");
+        for path in entries(&source).unwrap() {
+            let target = root.join(path.strip_prefix(&source).unwrap());
+            if path.is_dir() {
+                fs::create_dir_all(&target).unwrap();
+                for file in entries(&path).unwrap() {
+                    fs::copy(&file, target.join(file.file_name().unwrap())).unwrap();
+                }
+            } else {
+                fs::create_dir_all(&root).unwrap();
+                fs::copy(&path, &target).unwrap();
+            }
+        }
+        root
+    }
+
+    #[test]
+    fn embedded_content_is_the_merged_tree() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("content");
+        let options = Options {
+            overlays: split_names(OVERLAYS),
+            strip_drafts: !cfg!(debug_assertions),
+        };
+        let tree = load(&root, &options).unwrap();
+        assert_eq!(tree.merged, CONTENT_YAML);
+        assert_eq!(tree.content.default_lang().as_str(), "ru");
+    }
+
+    #[test]
+    fn errors_name_the_section_file() {
+        let root = scratch_tree("errors");
+        let skills = root.join("en/portfolio.yaml");
+        let source = fs::read_to_string(&skills).unwrap();
+        fs::write(
+            &skills,
+            source.replacen("level: \"expert\"", "level: \"master\"", 1),
+        )
+        .unwrap();
+        fs::remove_file(root.join("ru/footer.yaml")).unwrap();
+
+        let error = load(&root, &Options::default()).err().unwrap();
+        assert!(
+            error.ends_with("ru/footer.yaml: missing, and `ru` declares no fallback language"),
+            "{error}"
+        );
+
+        fs::write(root.join("ru/footer.yaml"), "copyright: ''\ncredits: ''\n").unwrap();
+        let error = load(&root, &Options::default()).err().unwrap();
+        let prefix = format!("{}:", skills.display());
+        assert!(error.starts_with(&prefix), "{error}");
+        assert!(error.contains("unknown variant `master`"), "{error}");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn technologies_of_draft_projects_only_leave_the_skills_of_release_builds() {
+        let root = scratch_tree("drafts");
+        let portfolio = root.join("ru/portfolio.yaml");
+        let source = fs::read_to_string(&portfolio).unwrap();
+        let pet_projects = "  - name: \"ПЕТ-ПРОЕКТЫ ДЛЯ РАЗВИТИЯ НАВЫКОВ\"\n";
+        assert!(source.contains(pet_projects));
+        let drafted = source.replacen(pet_projects, &format!("{pet_projects}    draft: true\n"), 1);
+        fs::write(&portfolio, drafted).unwrap();
+
+        let rust = |content: &Content| {
+            content
+                .languages()
+                .map(|(_, content)| {
+                    content
+                        .portfolio
+                        .skills
+                        .iter()
+                        .any(|skill| skill.technologies.contains(&"RUST".to_owned()))
+                })
+                .collect::<Vec<bool>>()
+        };
+        let debug = load(&root, &Options::default()).unwrap();
+        assert_eq!(rust(&debug.content), [true, true]);
+        let release = Options {
+            strip_drafts: true,
+            ..Options::default()
+        };
+        let release = load(&root, &release).unwrap();
+        assert_eq!(rust(&release.content), [false, false]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    fn with_overlay(name: &str) -> Options {
+        Options {
+            overlays: vec![name.to_owned()],
+            ..Options::default()
+        }
+    }
+
+    #[test]
+    fn overlay_errors_name_the_overlay_file() {
+        let root = scratch_tree("overlays");
+        let dir = root.join(OVERLAYS_DIR);
+        fs::create_dir_all(&dir).unwrap();
+        let overlay = dir.join("broken.yaml");
+
+        fs::write(&overlay, "remove: [\"*.contacts.phone\"]\n").unwrap();
+        let error = load(&root, &with_overlay("broken")).err().unwrap();
+        let expected = format!("{}: `*.contacts.phone` matches nothing", overlay.display());
+        assert_eq!(error, expected);
+
+        fs::write(
+            &overlay,
+            "set: {\"en.portfolio.skills[0].level\": \"master\"}\n",
+        )
+        .unwrap();
+        let error = load(&root, &with_overlay("broken")).err().unwrap();
+        assert!(
+            error.starts_with(&format!("{}:", overlay.display())),
+            "{error}"
+        );
+        assert!(error.contains("unknown variant `master`"), "{error}");
+
+        assert!(load(&root, &with_overlay("missing")).is_err());
+        assert!(load(&root, &with_overlay("../en")).is_err());
+        assert!(overlays(&root).unwrap().contains(&"broken".to_owned()));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,149 @@
+use serde::de::{self, Deserializer, Visitor};
+use std::fmt;
+
+// Checks run inside the visitors so serde_yaml reports the line and column of
+// the offending value.
+
+/// Deserialize a string that must contain at least one non-whitespace character
+pub fn non_empty<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    struct NonEmpty;
+
+    impl Visitor<'_> for NonEmpty {
+        type Value = String;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a non-empty string")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<String, E> {
+            if value.trim().is_empty() {
+                Err(E::custom("value must not be empty"))
+            } else {
+                Ok(value.to_owned())
+            }
+        }
+    }
+
+    deserializer.deserialize_str(NonEmpty)
+}
+
+/// Deserialize the twelve short month names of a language
+pub fn month_names<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    deserializer.deserialize_tuple(
+        12,
+        Strings {
+            min: 12,
+            max: 12,
+            what: "exactly 12 month names",
+        },
+    )
+}
+
+/// Deserialize one to three plural forms of a unit, e.g. `[год, года, лет]`
+pub fn plural_forms<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    deserializer.deserialize_tuple(
+        3,
+        Strings {
+            min: 1,
+            max: 3,
+            what: "one to three plural forms",
+        },
+    )
+}
+
+/// A list of words that belongs to one language as a whole. It is read as a
+/// tuple so translation fallback never pads it with entries of another
+/// language, which would turn two English plural forms into three.
+struct Strings {
+    min: usize,
+    max: usize,
+    what: &'static str,
+}
+
+impl<'de> Visitor<'de> for Strings {
+    type Value = Vec<String>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.what)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<String>, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element::<String>()? {
+            values.push(value);
+        }
+        if (self.min..=self.max).contains(&values.len()) {
+            Ok(values)
+        } else {
+            Err(de::Error::invalid_length(values.len(), &self))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug)]
+    struct Checked {
+        #[serde(default, deserialize_with = "super::non_empty")]
+        name: String,
+        #[serde(default, deserialize_with = "super::month_names")]
+        months: Vec<String>,
+        #[serde(default, deserialize_with = "super::plural_forms")]
+        years: Vec<String>,
+    }
+
+    fn error(yaml: &str) -> String {
+        serde_yaml::from_str::<Checked>(yaml)
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn rejects_empty_and_blank_strings() {
+        assert_eq!(
+            serde_yaml::from_str::<Checked>("name: Baza").unwrap().name,
+            "Baza"
+        );
+        assert_eq!(
+            error("name: ''"),
+            "name: value must not be empty at line 1 column 7"
+        );
+        assert_eq!(
+            error("name: '  '"),
+            "name: value must not be empty at line 1 column 7"
+        );
+        assert_eq!(
+            error("name: []"),
+            "name: invalid type: sequence, expected a non-empty string at line 1 column 7"
+        );
+    }
+
+    #[test]
+    fn counts_month_names_and_plural_forms() {
+        let months = "months: [a, b, c, d, e, f, g, h, i, j, k, l]";
+        assert_eq!(
+            serde_yaml::from_str::<Checked>(months)
+                .unwrap()
+                .months
+                .len(),
+            12
+        );
+        assert_eq!(
+            error("months: [a, b]"),
+            "months: invalid length 2, expected exactly 12 month names at line 1 column 9"
+        );
+
+        let years = serde_yaml::from_str::<Checked>("years: [год, года, лет]").unwrap();
+        assert_eq!(years.years, ["год", "года", "лет"]);
+        assert_eq!(
+            error("years: []"),
+            "years: invalid length 0, expected one to three plural forms at line 1 column 8"
+        );
+        assert_eq!(
+            error("years: [a, b, c, d]"),
+            "years: invalid length 4, expected one to three plural forms at line 1 column 8"
+        );
+    }
+}
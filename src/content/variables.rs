@@ -0,0 +1,177 @@
+use serde_yaml::Value;
+use std::collections::BTreeMap;
+
+use super::date::{Month, total_experience};
+use super::model::{Content, LangContent};
+
+// Text may reference `{name}` variables: the built-in ones below, computed when
+// content loads, and the constants a language declares under
+// `language.variables`. They are replaced in every string of the raw document
+// before it is parsed into the model, so rich text and HTML see the final text.
+// `{{` and `}}` stand for literal braces, any other brace is kept as is.
+
+/// Whole years of work experience, overlapping positions counted once
+pub const YEARS_EXPERIENCE: &str = "years_experience";
+
+/// Current calendar year, e.g. for the copyright line
+pub const CURRENT_YEAR: &str = "current_year";
+
+/// Values of all variables available to one language
+pub fn values(content: &LangContent, today: Month) -> Result<BTreeMap<&str, String>, String> {
+    let experience = total_experience(&content.about.work_experience, today);
+    let mut values = BTreeMap::from([
+        (YEARS_EXPERIENCE, experience.years.to_string()),
+        (CURRENT_YEAR, today.year().to_string()),
+    ]);
+    for (name, value) in &content.language.variables {
+        if !is_name(name) {
+            return Err(format!(
+                "language.variables.{name}: names use lowercase letters, digits and `_`"
+            ));
+        }
+        if values.contains_key(name.as_str()) {
+            return Err(format!(
+                "language.variables.{name}: `{name}` is a built-in variable"
+            ));
+        }
+        values.insert(name, value.clone());
+    }
+    Ok(values)
+}
+
+/// Replace variables in every string of the raw document, using the values
+/// computed from `content`, the document parsed as written
+pub fn resolve(raw: &mut Value, content: &Content, today: Month) -> Result<(), String> {
+    for (lang, lang_content) in content.languages() {
+        let values = values(lang_content, today).map_err(|err| format!("{lang}.{err}"))?;
+        let Some(Value::Mapping(sections)) = raw.get_mut(lang.as_str()) else {
+            continue;
+        };
+        for (section, value) in sections.iter_mut() {
+            let section = section.as_str().unwrap_or_default();
+            resolve_value(value, &format!("{lang}.{section}"), &values)?;
+        }
+    }
+    Ok(())
+}
+
+fn resolve_value(
+    value: &mut Value,
+    path: &str,
+    values: &BTreeMap<&str, String>,
+) -> Result<(), String> {
+    match value {
+        Value::String(text) => {
+            *text = substitute(text, values).map_err(|err| format!("{path}: {err}"))?;
+        }
+        Value::Sequence(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                resolve_value(item, &format!("{path}[{index}]"), values)?;
+            }
+        }
+        Value::Mapping(mapping) => {
+            for (key, item) in mapping.iter_mut() {
+                let key = key.as_str().unwrap_or_default();
+                let path = format!("{path}.{key}");
+                // Constants hold plain text and are substituted as they are
+                if path.ends_with(".language.variables") {
+                    continue;
+                }
+                resolve_value(item, &path, values)?;
+            }
+        }
+        Value::Tagged(tagged) => resolve_value(&mut tagged.value, path, values)?,
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+    Ok(())
+}
+
+/// Replace `{name}` in one string, failing on the first unknown variable
+pub fn substitute(text: &str, values: &BTreeMap<&str, String>) -> Result<String, String> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(['{', '}']) {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            out.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+        let name = tail[1..]
+            .split_once('}')
+            .map(|(name, _)| name)
+            .filter(|name| tail.starts_with('{') && is_name(name));
+        match name {
+            Some(name) => {
+                let value = values
+                    .get(name)
+                    .ok_or_else(|| format!("unknown variable `{{{name}}}`"))?;
+                out.push_str(value);
+                rest = &tail[name.len() + 2..];
+            }
+            None => {
+                out.push_str(&tail[..1]);
+                rest = &tail[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn is_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::content::CONTENT_YAML;
+
+    fn values() -> BTreeMap<&'static str, String> {
+        BTreeMap::from([
+            (YEARS_EXPERIENCE, "17".to_owned()),
+            ("servers", "2.5k".to_owned()),
+        ])
+    }
+
+    #[test]
+    fn substitutes_known_names_and_keeps_other_braces() {
+        assert_eq!(
+            substitute("{years_experience}+ years, {servers} servers", &values()).unwrap(),
+            "17+ years, 2.5k servers"
+        );
+        assert_eq!(
+            substitute("{{servers}} { x } {Servers} }", &values()).unwrap(),
+            "{servers} { x } {Servers} }"
+        );
+        assert_eq!(
+            substitute("{server}", &values()).err().unwrap(),
+            "unknown variable `{server}`"
+        );
+    }
+
+    #[test]
+    fn resolves_when_content_loads() {
+        let today = Month::new(2030, 3).unwrap();
+        let (content, _) = Content::from_yaml(CONTENT_YAML, today).unwrap();
+        for (_, content) in content.languages() {
+            assert!(content.footer.copyright.contains("2030"));
+            assert!(!content.about.skills.iter().any(|skill| skill.contains('{')));
+        }
+
+        let unknown = CONTENT_YAML.replacen("{current_year}", "{current_yaer}", 1);
+        let error = Content::from_yaml(&unknown, today)
+            .err()
+            .unwrap()
+            .to_string();
+        assert_eq!(
+            error,
+            "ru.footer.copyright: unknown variable `{current_yaer}`"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+//! Content model shared by the site and the `content` command-line tool
+
+pub mod content;
@@ -2,31 +2,56 @@ use yew::prelude::*;
 use yew_router::prelude::*;
 
 mod components;
-mod content;
 mod pages;
 
+use components::loading_screen::LoadingScreen;
+use content::Lang;
+use itmagelab::content;
+use pages::cv::Cv;
 use pages::home::Home;
+use yew::platform::spawn_local;
 
 #[derive(Clone, Routable, PartialEq)]
 pub enum Route {
-    #[at("/ru")]
-    Ru,
-    #[at("/en")]
-    En,
+    #[at("/:lang")]
+    Home { lang: Lang },
+    #[at("/:lang/cv")]
+    Cv { lang: Lang },
     #[at("/")]
     Root,
 }
 
 fn switch(routes: Route) -> Html {
     match routes {
-        Route::Ru => html! { <Home lang="ru" /> },
-        Route::En => html! { <Home lang="en" /> },
-        Route::Root => html! { <Redirect<Route> to={Route::Ru}/> },
+        Route::Home { lang } if content::has_lang(&lang) => html! { <Home {lang} /> },
+        Route::Cv { lang } if content::has_lang(&lang) => html! { <Cv {lang} /> },
+        Route::Home { .. } | Route::Cv { .. } | Route::Root => {
+            let lang = content::default_lang().clone();
+            html! { <Redirect<Route> to={Route::Home { lang }}/> }
+        }
     }
 }
 
 #[function_component(App)]
 fn app() -> Html {
+    let loading = use_state(|| content::content_url().is_some());
+
+    {
+        let loading = loading.clone();
+        use_effect_with((), move |_| {
+            if let Some(url) = content::content_url() {
+                spawn_local(async move {
+                    content::load_remote(url).await;
+                    loading.set(false);
+                });
+            }
+        });
+    }
+
+    if *loading {
+        return html! { <LoadingScreen /> };
+    }
+
     html! {
         <HashRouter>
             <Switch<Route> render={switch} />
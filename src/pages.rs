@@ -1 +1,2 @@
+pub mod cv;
 pub mod home;
@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::Route;
+use crate::components::draft_badge::DraftBadge;
+use crate::components::portfolio_section::project_meta;
+use crate::components::rich_text;
+use crate::content::{Draft, Lang, current_month, get_content};
+
+/// Query of the link that opens the print dialog once the page renders,
+/// e.g. `#/en/cv?print=true`
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct PrintQuery {
+    #[serde(default)]
+    pub print: bool,
+}
+
+// A4 pages with narrow margins, and entries kept whole across page breaks
+const PRINT_CSS: &str = "
+@page { size: A4; margin: 12mm 14mm; }
+@media print {
+    html, body { background: white; }
+    a { color: inherit; text-decoration: none; }
+}
+";
+
+#[derive(Properties, PartialEq)]
+pub struct CvProps {
+    pub lang: Lang,
+}
+
+/// Dense résumé on white for printing or saving as PDF
+#[function_component(Cv)]
+pub fn cv(props: &CvProps) -> Html {
+    let content = get_content(&props.lang);
+    let (hero, about, portfolio, contact) = (
+        &content.hero,
+        &content.about,
+        &content.portfolio,
+        &content.contact,
+    );
+    let dates = &content.language.dates;
+    let today = current_month();
+
+    let print_now = use_location()
+        .and_then(|location| location.query::<PrintQuery>().ok())
+        .is_some_and(|query| query.print);
+    use_effect_with(print_now, |print_now| {
+        if *print_now && let Some(window) = web_sys::window() {
+            let _ = window.print();
+        }
+    });
+    let print = Callback::from(|_: MouseEvent| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.print();
+        }
+    });
+
+    let contacts: Vec<(String, String)> = contact
+        .phone
+        .iter()
+        .map(|phone| {
+            let number = phone.replace(&[' ', '(', ')', '-'][..], "");
+            (format!("tel:{number}"), phone.clone())
+        })
+        .chain(std::iter::once((
+            format!("mailto:{}", contact.email),
+            contact.email.clone(),
+        )))
+        .chain(contact.social_networks.iter().map(|network| {
+            let shown = network.url.trim_start_matches("https://").to_owned();
+            (network.url.clone(), shown)
+        }))
+        .collect();
+
+    let section_title = "text-lg font-bold uppercase tracking-wider text-gray-800 border-b-2 border-gray-800 mb-3 break-after-avoid";
+
+    html! {
+        <div class="bg-white min-h-screen text-gray-900 font-sansation print:min-h-0">
+            <style>{ PRINT_CSS }</style>
+
+            <nav class="print:hidden bg-gray-800 px-4 py-3">
+                <div class="max-w-4xl mx-auto flex justify-between items-center gap-4">
+                    <Link<Route>
+                        to={Route::Home { lang: props.lang.clone() }}
+                        classes="text-green-300 font-semibold hover:text-green-400"
+                    >
+                        { &about.cv.back }
+                    </Link<Route>>
+                    <button
+                        type="button"
+                        onclick={print}
+                        class="bg-green-300 text-gray-800 font-semibold rounded-lg px-4 py-2 hover:bg-green-400 transition-colors"
+                    >
+                        { &about.cv.print }
+                    </button>
+                </div>
+            </nav>
+
+            <main class="max-w-4xl mx-auto px-6 py-8 print:p-0 text-sm leading-snug space-y-6">
+                <header class="flex flex-col sm:flex-row sm:justify-between sm:items-end gap-2 border-b-4 border-gray-800 pb-3">
+                    <div>
+                        <h1 class="text-3xl font-bold text-gray-900">{ &hero.subtitle }</h1>
+                        <p class="text-base text-gray-700">{ &hero.title }</p>
+                    </div>
+                    <ul class="sm:text-right text-gray-700">
+                        {
+                            contacts.into_iter().map(|(href, shown)| html! {
+                                <li><a {href} class="hover:underline">{ shown }</a></li>
+                            }).collect::<Html>()
+                        }
+                    </ul>
+                </header>
+
+                <section>
+                    <h2 class={section_title}>{ &about.title }</h2>
+                    <p class="whitespace-pre-line">{ about.description.trim() }</p>
+                    <ul class="list-disc list-inside mt-2">
+                        { about.skills.iter().map(|item| html! { <li>{ item }</li> }).collect::<Html>() }
+                    </ul>
+                </section>
+
+                <section>
+                    <h2 class={section_title}>{ &about.work_experience_title }</h2>
+                    <div class="space-y-3">
+                        {
+                            about.work_experience.iter().map(|exp| html! {
+                                <article class="break-inside-avoid">
+                                    <div class="flex justify-between gap-4">
+                                        <h3 class="font-bold">
+                                            { format!("{} — {}", exp.position, exp.company) }
+                                            <DraftBadge draft={exp.is_draft()} />
+                                        </h3>
+                                        <span class="text-gray-700 whitespace-nowrap">
+                                            { format!("{} ({})", dates.range(exp.start, exp.end), dates.duration(exp.duration(today))) }
+                                        </span>
+                                    </div>
+                                    <ul class="list-disc ml-5">
+                                        {
+                                            exp.description.iter().map(|text| html! {
+                                                <li>{ rich_text::render(text) }</li>
+                                            }).collect::<Html>()
+                                        }
+                                    </ul>
+                                </article>
+                            }).collect::<Html>()
+                        }
+                    </div>
+                </section>
+
+                <section>
+                    <h2 class={section_title}>{ &portfolio.projects_title }</h2>
+                    <div class="space-y-3">
+                        {
+                            portfolio.projects.iter().map(|project| html! {
+                                <article class="break-inside-avoid">
+                                    <div class="flex justify-between gap-4">
+                                        <h3 class="font-bold">
+                                            { &project.name }
+                                            <DraftBadge draft={project.is_draft()} />
+                                        </h3>
+                                        <span class="text-gray-700 text-right">
+                                            { project_meta(project, dates).unwrap_or_default() }
+                                        </span>
+                                    </div>
+                                    {
+                                        project.description.iter().map(|text| html! {
+                                            <p>{ rich_text::render(text) }</p>
+                                        }).collect::<Html>()
+                                    }
+                                    <p class="text-gray-700 italic">{ project.technologies.join(", ") }</p>
+                                </article>
+                            }).collect::<Html>()
+                        }
+                    </div>
+                </section>
+
+                <section class="break-inside-avoid">
+                    <h2 class={section_title}>{ &portfolio.skills_title }</h2>
+                    <dl class="grid grid-cols-1 sm:grid-cols-2 print:grid-cols-2 gap-x-6 gap-y-2">
+                        {
+                            portfolio.skills_by_category().into_iter().map(|(category, skills)| {
+                                let skills: Vec<String> = skills.iter().map(|skill| portfolio.skill_label(skill)).collect();
+                                html! {
+                                    <div class="break-inside-avoid">
+                                        <dt class="font-bold">{ &category.name }</dt>
+                                        <dd class="text-gray-800">{ skills.join(", ") }</dd>
+                                    </div>
+                                }
+                            }).collect::<Html>()
+                        }
+                    </dl>
+                </section>
+            </main>
+        </div>
+    }
+}
@@ -4,11 +4,12 @@ use crate::components::footer_section::FooterSection;
 use crate::components::hero_section::HeroSection;
 use crate::components::language_switcher::LanguageSwitcher;
 use crate::components::portfolio_section::PortfolioSection;
+use crate::content::Lang;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct HomeProps {
-    pub lang: String,
+    pub lang: Lang,
 }
 
 #[function_component(Home)]